@@ -1,4 +1,5 @@
 use std::borrow::BorrowMut;
+use std::collections::{HashMap, HashSet};
 
 use crossterm::event::{self, KeyCode};
 use ratatui::{
@@ -11,43 +12,107 @@ use ratatui::{
 };
 
 use anyhow::Result;
+use chrono::{prelude::*, Duration};
+use itertools::Itertools;
 
 use std::path::PathBuf;
 
 use crate::{
+    config::Config,
+    export::{export_report, registry, Exporter, SummaryExporter},
+    history::History,
+    issue::IssueKeyMatcher,
+    paths::AppPaths,
+    plan::PlannedItem,
+    report::{Report, ReportDay},
     state::{self, DailyState},
+    summary::Summary,
+    tag_model::{save_tag_corrections, TagPredictor},
     task::NotCompletedTask,
-    ui::{tabs::Tab, AppStage, AppUiState, Control},
-    utils::{calculate_total_working_hours, set_task_name_from_previous_tasks},
+    templates::Templates,
+    time_expression::parse_time_expression,
+    ui::{
+        control::FormatSelect,
+        forgotten_task::{ForgottenTaskAction, ForgottenTaskPrompt, ForgottenTaskReason},
+        summary_popup::SummaryPopup,
+        tabs::Tab,
+        tag_review::{TagReviewPopup, TagReviewRow},
+        AppStage, AppUiState, Control,
+    },
+    utils::{
+        calculate_total_working_hours, set_task_name_from_previous_tasks, MILLISECONDS_IN_HOUR,
+    },
 };
 
-use ort::{GraphOptimizationLevel, Session};
-
 pub struct App {
     daily_state: DailyState,
     ui_state: AppUiState,
     should_quit: bool,
     state_file_path: PathBuf,
-    prediction_model_session: Session,
+    output_dir: PathBuf,
+    tag_predictor: TagPredictor,
+    tag_corrections_file: PathBuf,
+    history: History,
+    config: Config,
+    exporters: Vec<Box<dyn Exporter>>,
+    issue_key_matcher: IssueKeyMatcher,
+    templates: Templates,
+    last_activity: DateTime<Local>,
+    pending_idle_start: Option<DateTime<Local>>,
+    long_running_prompted_for: Option<DateTime<Local>>,
 }
 
 impl App {
-    pub fn init(state_file_path: &PathBuf) -> App {
-        let prediction_model_bytes = include_bytes!("./../model/tag_predict_model.onnx");
-
-        let daily_state: DailyState = state::DailyState::init(state_file_path).unwrap();
+    pub fn init(paths: &AppPaths) -> App {
+        let daily_state: DailyState = state::DailyState::init(&paths.state_file).unwrap();
+
+        let mut startup_errors = vec![];
+
+        let config = Config::init(&paths.config_file).unwrap_or_else(|err| {
+            startup_errors.push(err);
+            Config::default()
+        });
+        let issue_key_matcher =
+            IssueKeyMatcher::init(&config.issue_key_patterns).unwrap_or_else(|err| {
+                startup_errors.push(err);
+                IssueKeyMatcher::default()
+            });
+        let templates = Templates::init(&paths.templates_file).unwrap_or_else(|err| {
+            startup_errors.push(err);
+            Templates::default()
+        });
+        let history = History::init(&paths.history_dir).unwrap();
+        let exporters = registry(&config);
+        let tag_predictor = TagPredictor::init(&config.tag_model, &paths.tag_cache_file);
+        let available_formats = exporters
+            .iter()
+            .map(|exporter| exporter.name().to_owned())
+            .collect();
+
+        let mut ui_state = AppUiState::init(available_formats, &config.export_formats);
+
+        startup_errors.extend(tag_predictor.load_errors.iter().cloned());
+
+        if !startup_errors.is_empty() {
+            ui_state.status_message = Some(startup_errors.join(". "));
+        }
 
         App {
-            ui_state: AppUiState::init(),
+            ui_state,
             daily_state,
             should_quit: false,
-            state_file_path: state_file_path.clone(),
-            prediction_model_session: Session::builder()
-                .unwrap()
-                .with_optimization_level(GraphOptimizationLevel::Level3)
-                .unwrap()
-                .commit_from_memory(prediction_model_bytes)
-                .unwrap(),
+            state_file_path: paths.state_file.clone(),
+            output_dir: paths.output_dir.clone(),
+            tag_predictor,
+            tag_corrections_file: paths.tag_corrections_file.clone(),
+            history,
+            config,
+            exporters,
+            issue_key_matcher,
+            templates,
+            last_activity: Local::now(),
+            pending_idle_start: None,
+            long_running_prompted_for: None,
         }
     }
 
@@ -55,13 +120,88 @@ impl App {
         if event::poll(std::time::Duration::from_millis(250))? {
             if let event::Event::Key(key) = event::read()? {
                 if key.kind == event::KeyEventKind::Press {
+                    // A message stays until the next key, which may set a new one
+                    self.ui_state.status_message = None;
+
+                    // The key that ends an idle period only wakes the prompt up, it must not act on
+                    // the UI hidden behind it
+                    if self.register_activity() {
+                        self.check_forgotten_task();
+
+                        if self.ui_state.forgotten_task_prompt.is_some() {
+                            return Ok(());
+                        }
+                    }
+
+                    if self.ui_state.forgotten_task_prompt.is_some() {
+                        self.handle_forgotten_task_prompt_key(key.code);
+
+                        return Ok(());
+                    }
+
+                    if self.ui_state.summary_popup.is_some() {
+                        self.handle_summary_popup_key(key.code);
+
+                        return Ok(());
+                    }
+
+                    if self.ui_state.tag_review_popup.is_some() {
+                        self.handle_tag_review_key(key.code);
+
+                        return Ok(());
+                    }
+
                     match key.code {
                         KeyCode::Esc => self.quit(),
                         KeyCode::Right => self.ui_state.switch_tabs_forward(),
                         KeyCode::Left => self.ui_state.switch_tabs_backward(),
-                        KeyCode::Down => self.ui_state.switch_control_focus_forwards(),
+                        KeyCode::Tab => {
+                            if let Tab::Start = self.ui_state.get_active_tab() {
+                                self.ui_state.tag_suggestion.accept();
+                            }
+
+                            self.with_focused_format_select(FormatSelect::move_cursor_forwards);
+                        }
+                        KeyCode::BackTab => {
+                            if let Tab::Start = self.ui_state.get_active_tab() {
+                                self.ui_state
+                                    .tag_suggestion
+                                    .choose_next(&self.config.tag_model.known_tags);
+                            }
+
+                            self.with_focused_format_select(FormatSelect::move_cursor_backwards);
+                        }
+                        KeyCode::Down => {
+                            if let Tab::Plan = self.ui_state.get_active_tab() {
+                                let items_count = self.daily_state.plan.items.len();
+                                self.ui_state.select_next_plan_item(items_count);
+
+                                return Ok(());
+                            }
+
+                            if let Tab::Templates = self.ui_state.get_active_tab() {
+                                let templates_count = self.templates.templates.len();
+                                self.ui_state.select_next_template(templates_count);
+
+                                return Ok(());
+                            }
+
+                            self.ui_state.switch_control_focus_forwards()
+                        }
                         // KeyCode::Up => self.ui_state.switch_control_focus_backwards(),
                         KeyCode::Up => {
+                            if let Tab::Plan = self.ui_state.get_active_tab() {
+                                self.ui_state.select_previous_plan_item();
+
+                                return Ok(());
+                            }
+
+                            if let Tab::Templates = self.ui_state.get_active_tab() {
+                                self.ui_state.select_previous_template();
+
+                                return Ok(());
+                            }
+
                             let active_control = &self.ui_state.control_focused;
 
                             if let Some(ref control_mutex) = active_control {
@@ -84,13 +224,46 @@ impl App {
 
                             self.ui_state.switch_control_focus_backwards()
                         }
-                        KeyCode::Enter => self.submit(),
+                        KeyCode::Enter => {
+                            if let Tab::Plan = self.ui_state.get_active_tab() {
+                                self.submit_plan_tab();
+
+                                return Ok(());
+                            }
+
+                            if let Tab::Templates = self.ui_state.get_active_tab() {
+                                let selected_template = self.ui_state.selected_template;
+
+                                if let Some(template) =
+                                    self.templates.templates.get(selected_template)
+                                {
+                                    let new_task = template.start_task();
+                                    self.start_task(new_task, None);
+                                }
+
+                                return Ok(());
+                            }
+
+                            self.submit()
+                        }
+                        KeyCode::Delete => {
+                            if let Tab::Plan = self.ui_state.get_active_tab() {
+                                let selected_plan_item = self.ui_state.selected_plan_item;
+
+                                self.daily_state.plan.remove_item(selected_plan_item);
+                                self.ui_state.select_previous_plan_item();
+
+                                let _ = self.daily_state.save(&self.state_file_path);
+                            }
+                        }
                         KeyCode::Backspace => {
                             if let Some(control_focused_mutex) = &self.ui_state.control_focused {
                                 let input = &mut *control_focused_mutex.lock().unwrap();
                                 match input {
                                     Control::TaskNameInput(control)
-                                    | Control::EndCommentInput(control) => {
+                                    | Control::EndCommentInput(control)
+                                    | Control::PlanItemInput(control)
+                                    | Control::StartTimeInput(control) => {
                                         control.borrow_mut().remove_last_char_from_input()
                                     }
                                     _ => {}
@@ -99,12 +272,68 @@ impl App {
                         }
                         _ => {
                             if let KeyCode::Char(char) = key.code {
+                                if char == ' '
+                                    && self.with_focused_format_select(
+                                        FormatSelect::toggle_format_under_cursor,
+                                    )
+                                {
+                                    return Ok(());
+                                }
+
+                                if let Some(number) = char.to_digit(10) {
+                                    if let Some(control_focused_mutex) =
+                                        &self.ui_state.control_focused
+                                    {
+                                        let control = &mut *control_focused_mutex.lock().unwrap();
+
+                                        if let Control::ExportFormats(state) = control {
+                                            state.toggle_format(number as usize);
+
+                                            return Ok(());
+                                        }
+                                    }
+                                }
+
+                                if char == 's' && !self.is_text_input_focused() {
+                                    self.open_summary_popup();
+
+                                    return Ok(());
+                                }
+
+                                if char == 'x' && !self.is_text_input_focused() {
+                                    let formats = self.get_selected_export_formats();
+
+                                    self.ui_state.status_message =
+                                        Some(match self.execute_export_now_command(formats) {
+                                            Ok(message) => message,
+                                            Err(err) => err,
+                                        });
+
+                                    return Ok(());
+                                }
+
+                                let favourite_number =
+                                    char.to_digit(10).filter(|_| !self.is_text_input_focused());
+
+                                if let Some(number) = favourite_number {
+                                    if let Some(template) =
+                                        self.templates.get_favourite(number as usize)
+                                    {
+                                        let new_task = template.start_task();
+                                        self.start_task(new_task, None);
+                                    }
+
+                                    return Ok(());
+                                }
+
                                 if let Some(control_focused_mutex) = &self.ui_state.control_focused
                                 {
                                     let input = &mut *control_focused_mutex.lock().unwrap();
                                     match input {
                                         Control::TaskNameInput(control)
-                                        | Control::EndCommentInput(control) => {
+                                        | Control::EndCommentInput(control)
+                                        | Control::PlanItemInput(control)
+                                        | Control::StartTimeInput(control) => {
                                             control.borrow_mut().add_char_to_input(char)
                                         }
                                         _ => {}
@@ -133,24 +362,54 @@ impl App {
                     Tab::Start => {
                         drop(control);
 
-                        let task_name = self.get_task_name_input().unwrap();
+                        let task_name = self.get_task_name_input();
                         let end_comment = self.get_end_comment_input();
-                        let _ = self.execute_start_command(task_name, end_comment);
+                        let dt_start = match self.get_start_time_input() {
+                            Some(expression) => parse_time_expression(
+                                &expression,
+                                Local::now(),
+                                self.daily_state.start_time,
+                            )
+                            .map(Some),
+                            None => Ok(None),
+                        };
+
+                        match (task_name, dt_start) {
+                            (Ok(task_name), Ok(dt_start)) => {
+                                let mut new_task = NotCompletedTask::start(task_name);
+                                new_task.tag = self.ui_state.tag_suggestion.chosen_tag.to_owned();
+
+                                if let Some(dt_start) = dt_start {
+                                    new_task.dt_start = dt_start;
+                                }
 
-                        let current_stage = &mut self.ui_state.stage;
-                        if let AppStage::Waiting = current_stage {
-                            *current_stage = AppStage::Working
+                                self.start_task(new_task, end_comment);
+                            }
+                            (Err(_), Ok(Some(dt_start))) => {
+                                self.ui_state.status_message =
+                                    Some(match self.execute_edit_start_command(dt_start) {
+                                        Ok(message) => message,
+                                        Err(err) => err,
+                                    });
+                            }
+                            // The inputs are kept, so a typo can be fixed
+                            (Err(err), Ok(None)) | (_, Err(err)) => {
+                                self.ui_state.status_message = Some(err);
+
+                                return;
+                            }
                         }
                     }
                     Tab::End => {
                         drop(control);
 
                         let end_comment = self.get_end_comment_input();
-                        let _ = self.execute_end_command(end_comment);
+                        let formats = self.get_selected_export_formats();
 
-                        let current_stage = &mut self.ui_state.stage;
-                        if let AppStage::Working = current_stage {
-                            *current_stage = AppStage::Waiting
+                        if self.config.tag_review.enabled {
+                            self.open_tag_review(end_comment, formats);
+                        } else {
+                            self.end_day(end_comment, formats, HashMap::new(), Local::now());
                         }
                     }
                     Tab::Out => {
@@ -165,7 +424,9 @@ impl App {
                         }
                     }
                     Tab::ClearState => {
-                        let _ = self.daily_state.clear_todays_state(&self.state_file_path);
+                        let _ = self
+                            .daily_state
+                            .clear_todays_state(&self.state_file_path, &self.history);
 
                         ui_state.stage = AppStage::Waiting;
                     }
@@ -177,6 +438,438 @@ impl App {
         self.ui_state.clear_inputs_state();
     }
 
+    /// Returns true when the activity ends an idle period
+    fn register_activity(&mut self) -> bool {
+        let now = Local::now();
+        let idle_threshold = Duration::minutes(self.config.forgotten_task.idle_minutes.into());
+        let was_idle = now - self.last_activity >= idle_threshold;
+
+        if was_idle {
+            self.pending_idle_start = Some(self.last_activity);
+        }
+
+        self.last_activity = now;
+
+        was_idle
+    }
+
+    fn check_forgotten_task(&mut self) {
+        let settings = &self.config.forgotten_task;
+
+        if !settings.enabled || self.ui_state.forgotten_task_prompt.is_some() {
+            return;
+        }
+
+        let pending_idle_start = self.pending_idle_start.take();
+
+        if let Some(task) = &self.daily_state.current_task {
+            let now = Local::now();
+            let max_running_time =
+                Duration::milliseconds((settings.max_hours * MILLISECONDS_IN_HOUR) as i64);
+
+            let prompt = match pending_idle_start {
+                Some(idle_start) if task.dt_start < idle_start => Some(ForgottenTaskPrompt::init(
+                    ForgottenTaskReason::Idle,
+                    task.name.to_owned(),
+                    task.dt_start,
+                    idle_start,
+                )),
+                _ if now - task.dt_start > max_running_time
+                    && self.long_running_prompted_for != Some(task.dt_start) =>
+                {
+                    self.long_running_prompted_for = Some(task.dt_start);
+
+                    Some(ForgottenTaskPrompt::init(
+                        ForgottenTaskReason::LongRunning,
+                        task.name.to_owned(),
+                        task.dt_start,
+                        task.dt_start + max_running_time,
+                    ))
+                }
+                _ => None,
+            };
+
+            self.ui_state.forgotten_task_prompt = prompt;
+        }
+    }
+
+    fn handle_forgotten_task_prompt_key(&mut self, key_code: KeyCode) {
+        if let Some(prompt) = &mut self.ui_state.forgotten_task_prompt {
+            match key_code {
+                KeyCode::Up => prompt.select_previous_action(),
+                KeyCode::Down => prompt.select_next_action(),
+                KeyCode::Backspace => prompt.time_input.remove_last_char_from_input(),
+                KeyCode::Char(char) => prompt.time_input.add_char_to_input(char),
+                KeyCode::Esc => self.ui_state.forgotten_task_prompt = None,
+                KeyCode::Enter => {
+                    let action = prompt.selected_action;
+                    let chosen_time = parse_time_expression(
+                        &prompt.time_input.input,
+                        Local::now(),
+                        self.daily_state.start_time,
+                    );
+
+                    let result = match (action, chosen_time) {
+                        (ForgottenTaskAction::Keep, _) => Ok(None),
+                        (ForgottenTaskAction::End, Ok(dt_end)) => {
+                            self.execute_stop_at_command(dt_end, None).map(|message| {
+                                self.ui_state.stage = AppStage::Paused;
+
+                                Some(message)
+                            })
+                        }
+                        (ForgottenTaskAction::Split, Ok(dt_end)) => self.split_current_task(dt_end),
+                        (_, Err(err)) => Err(err),
+                    };
+
+                    match result {
+                        Ok(message) => {
+                            self.ui_state.forgotten_task_prompt = None;
+
+                            if message.is_some() {
+                                self.ui_state.status_message = message;
+                            }
+                        }
+                        Err(err) => self.ui_state.status_message = Some(err),
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Ends the running task at `dt_end` and continues it from now. The idle gap between them is
+    /// written into the end comment of the first part, so the report shows why time is missing
+    fn split_current_task(&mut self, dt_end: DateTime<Local>) -> Result<Option<String>, String> {
+        let Some(mut new_task) = self.daily_state.current_task.clone() else {
+            return Ok(None);
+        };
+
+        let now = Local::now();
+        let idle_gap = format!("Idle {}-{}", dt_end.format("%H:%M"), now.format("%H:%M"));
+
+        self.execute_stop_at_command(dt_end, Some(idle_gap.to_owned()))?;
+
+        new_task.dt_start = now;
+
+        self.execute_start_command(new_task, None)?;
+
+        Ok(Some(format!("Task split, {} is not tracked", idle_gap)))
+    }
+
+    /// Opens the summary of the last archived day, today is shown when nothing is archived yet
+    fn open_summary_popup(&mut self) {
+        let today = self.daily_state.start_time.date_naive();
+
+        let mut dates = self.history.archived_dates().unwrap_or_default();
+        dates.retain(|date| *date < today);
+        dates.push(today);
+
+        let selected_date = dates.len().saturating_sub(2);
+        let summary = self.get_summary(dates[selected_date]);
+
+        self.ui_state.summary_popup = Some(SummaryPopup::init(dates, selected_date, summary));
+    }
+
+    fn handle_summary_popup_key(&mut self, key_code: KeyCode) {
+        let Some(popup) = &self.ui_state.summary_popup else {
+            return;
+        };
+
+        let selected_date = match key_code {
+            KeyCode::Left => popup.selected_date.saturating_sub(1),
+            KeyCode::Right => (popup.selected_date + 1).min(popup.dates.len() - 1),
+            _ => popup.selected_date,
+        };
+        let date = popup.dates[selected_date];
+
+        match key_code {
+            KeyCode::Esc => self.ui_state.summary_popup = None,
+            KeyCode::Left | KeyCode::Right => {
+                let summary = self.get_summary(date);
+
+                if let Some(popup) = &mut self.ui_state.summary_popup {
+                    popup.selected_date = selected_date;
+                    popup.summary = summary;
+                    popup.scroll = 0;
+                    popup.message = None;
+                }
+            }
+            KeyCode::Char('w') => {
+                let exporter = SummaryExporter {
+                    format: popup.format,
+                };
+                let report = self.get_summary_report(date);
+
+                let message = match exporter.export(&report, &self.output_dir) {
+                    Ok(file_path) => format!("Saved to {}", file_path),
+                    Err(err) => err,
+                };
+
+                if let Some(popup) = &mut self.ui_state.summary_popup {
+                    popup.message = Some(message);
+                }
+            }
+            _ => {
+                if let Some(popup) = &mut self.ui_state.summary_popup {
+                    match key_code {
+                        KeyCode::Up => popup.scroll_up(),
+                        KeyCode::Down => popup.scroll_down(),
+                        KeyCode::Tab => popup.toggle_format(),
+                        _ => {}
+                    }
+                }
+            }
+        }
+    }
+
+    /// Shows the tags of the day, predicted ones included, before End writes the report
+    fn open_tag_review(&mut self, end_comment: Option<String>, formats: Vec<String>) {
+        // The running task ends at the time shown in the review, so its id matches the reviewed tags
+        let ended_at = Local::now();
+        let day = ReportDay::snapshot(&self.daily_state, ended_at, &self.issue_key_matcher);
+
+        if day.tasks.is_empty() {
+            self.end_day(end_comment, formats, HashMap::new(), ended_at);
+
+            return;
+        }
+
+        let untagged_ids: HashSet<String> = day
+            .tasks
+            .iter()
+            .filter(|task| task.tag.is_none())
+            .map(|task| task.id())
+            .collect();
+
+        let mut report = Report::from_days(vec![day]);
+        report.assign_predicted_tags(&self.tag_predictor);
+
+        let rows = report
+            .tasks()
+            .map(|task| TagReviewRow::from_task(task, untagged_ids.contains(&task.id())))
+            .collect();
+
+        self.ui_state.tag_review_popup =
+            Some(TagReviewPopup::init(rows, end_comment, formats, ended_at));
+    }
+
+    fn handle_tag_review_key(&mut self, key_code: KeyCode) {
+        let Some(popup) = &mut self.ui_state.tag_review_popup else {
+            return;
+        };
+
+        let known_tags = &self.config.tag_model.known_tags;
+
+        match key_code {
+            KeyCode::Up => popup.select_previous_row(),
+            KeyCode::Down => popup.select_next_row(),
+            KeyCode::Right | KeyCode::Tab => popup.change_tag(known_tags, 1),
+            KeyCode::Left | KeyCode::BackTab => popup.change_tag(known_tags, -1),
+            KeyCode::Backspace | KeyCode::Delete => popup.reset_tag(),
+            KeyCode::Esc => self.ui_state.tag_review_popup = None,
+            KeyCode::Enter => {
+                let Some(popup) = self.ui_state.tag_review_popup.take() else {
+                    return;
+                };
+
+                let reviewed_tags = popup.reviewed_tags();
+                let corrections: Vec<(String, String)> = popup
+                    .rows
+                    .iter()
+                    .filter(|row| row.is_overridden())
+                    .filter_map(|row| Some((row.task_name.to_owned(), row.tag.to_owned()?)))
+                    .collect();
+
+                let is_ended = self.end_day(
+                    popup.end_comment,
+                    popup.formats,
+                    reviewed_tags,
+                    popup.ended_at,
+                );
+
+                // Nothing is learned from a day that was not ended
+                if is_ended && self.config.tag_review.save_corrections && !corrections.is_empty() {
+                    if let Err(err) = save_tag_corrections(&self.tag_corrections_file, &corrections)
+                    {
+                        self.ui_state.status_message =
+                            Some(match self.ui_state.status_message.take() {
+                                Some(message) => format!("{}. {}", message, err),
+                                None => err,
+                            });
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// True when the day is archived
+    fn end_day(
+        &mut self,
+        end_comment: Option<String>,
+        formats: Vec<String>,
+        reviewed_tags: HashMap<String, String>,
+        dt_end: DateTime<Local>,
+    ) -> bool {
+        let result = self.execute_end_command(end_comment, formats, reviewed_tags, dt_end);
+
+        let current_stage = &mut self.ui_state.stage;
+
+        match result {
+            Ok(message) => {
+                *current_stage = AppStage::Waiting;
+                self.ui_state.status_message = Some(message);
+
+                true
+            }
+            Err(err) => {
+                // The day is not archived, but the running task may already be completed
+                if self.daily_state.current_task.is_none() {
+                    if let AppStage::Working = current_stage {
+                        *current_stage = AppStage::Paused
+                    }
+                }
+
+                self.ui_state.status_message = Some(err);
+
+                false
+            }
+        }
+    }
+
+    fn get_summary_report(&self, date: NaiveDate) -> Report {
+        let day = if date == self.daily_state.start_time.date_naive() {
+            Some(ReportDay::from_daily_state(&self.daily_state))
+        } else {
+            self.history.load_day(date).ok().flatten()
+        };
+
+        let mut report = Report::from_days(day.into_iter().collect());
+        report.assign_predicted_tags(&self.tag_predictor);
+
+        report
+    }
+
+    fn get_summary(&self, date: NaiveDate) -> Summary {
+        match self.get_summary_report(date).days.first() {
+            Some(day) => Summary::from_day(day),
+            None => Summary {
+                date,
+                groups: vec![],
+            },
+        }
+    }
+
+    fn start_task(
+        &mut self,
+        new_task: NotCompletedTask,
+        previous_task_completion_message: Option<String>,
+    ) {
+        if let Err(err) = self.execute_start_command(new_task, previous_task_completion_message) {
+            self.ui_state.status_message = Some(err);
+
+            return;
+        }
+
+        let current_stage = &mut self.ui_state.stage;
+        if let AppStage::Waiting = current_stage {
+            *current_stage = AppStage::Working
+        }
+    }
+
+    fn submit_plan_tab(&mut self) {
+        let mut plan_item_input = self.ui_state.plan_item_input.lock().unwrap();
+
+        if let Control::PlanItemInput(state) = &mut *plan_item_input {
+            if !state.input.is_empty() {
+                match PlannedItem::parse(&state.input) {
+                    Ok(item) => {
+                        self.daily_state.plan.add_item(item);
+
+                        let _ = self.daily_state.save(&self.state_file_path);
+
+                        state.input = String::new();
+                    }
+                    // The input is kept, so a typo in the estimate can be fixed
+                    Err(err) => self.ui_state.status_message = Some(err),
+                }
+
+                return;
+            }
+        }
+
+        drop(plan_item_input);
+
+        let selected_plan_item = self.ui_state.selected_plan_item;
+
+        if let Some(item) = self.daily_state.plan.items.get(selected_plan_item) {
+            let new_task = item.start_task();
+            self.start_task(new_task, None);
+        }
+    }
+
+    /// Predicts the tag once the task name on the Start tab stopped changing
+    fn update_tag_suggestion(&mut self) {
+        if !matches!(self.ui_state.get_active_tab(), Tab::Start) {
+            return;
+        }
+
+        let task_name = self.get_task_name_input().unwrap_or_default();
+
+        if self.ui_state.tag_suggestion.observe_input(&task_name) {
+            self.ui_state.tag_suggestion.suggested_tag = if task_name.trim().is_empty() {
+                None
+            } else {
+                self.tag_predictor.suggest(&task_name)
+            };
+        }
+    }
+
+    /// Returns false when the export formats are not focused
+    fn with_focused_format_select(&self, action: impl FnOnce(&mut FormatSelect)) -> bool {
+        if let Some(control_focused_mutex) = &self.ui_state.control_focused {
+            if let Control::ExportFormats(state) = &mut *control_focused_mutex.lock().unwrap() {
+                action(state);
+
+                return true;
+            }
+        }
+
+        false
+    }
+
+    fn show_tag_prediction_errors(&mut self) {
+        let errors = self.tag_predictor.take_runtime_errors();
+
+        if !errors.is_empty() {
+            // Added to the message of the action that ran the prediction
+            self.ui_state.status_message = Some(
+                self.ui_state
+                    .status_message
+                    .iter()
+                    .chain(&errors)
+                    .join(". "),
+            );
+        }
+    }
+
+    fn is_text_input_focused(&self) -> bool {
+        if let Some(control_focused_mutex) = &self.ui_state.control_focused {
+            let control = control_focused_mutex.lock().unwrap();
+
+            matches!(
+                *control,
+                Control::TaskNameInput(_)
+                    | Control::EndCommentInput(_)
+                    | Control::PlanItemInput(_)
+                    | Control::StartTimeInput(_)
+            )
+        } else {
+            false
+        }
+    }
+
     fn get_task_name_input(&mut self) -> Result<String, String> {
         let task_name_input_lock = self.ui_state.task_name_input.lock();
         let task_name_guard = task_name_input_lock.unwrap();
@@ -193,6 +886,33 @@ impl App {
         }
     }
 
+    fn get_start_time_input(&mut self) -> Option<String> {
+        let start_time_input_lock = self.ui_state.task_start_time_input.lock();
+        let start_time_guard = start_time_input_lock.unwrap();
+
+        if let Control::StartTimeInput(state) = &*start_time_guard {
+            let input = state.input.trim();
+
+            if input.is_empty() {
+                None
+            } else {
+                Some(input.to_owned())
+            }
+        } else {
+            unreachable!()
+        }
+    }
+
+    fn get_selected_export_formats(&mut self) -> Vec<String> {
+        let export_formats_guard = self.ui_state.export_formats_select.lock().unwrap();
+
+        if let Control::ExportFormats(state) = &*export_formats_guard {
+            state.selected_formats()
+        } else {
+            unreachable!()
+        }
+    }
+
     fn get_end_comment_input(&mut self) -> Option<String> {
         let previous_task_comment_input_lock = self.ui_state.task_end_comment_input.lock();
         let end_comment_guard = previous_task_comment_input_lock.unwrap();
@@ -288,7 +1008,10 @@ impl App {
         );
 
         let current_task_name = if let Some(task) = current_task {
-            task.name.to_owned()
+            match task.planned_minutes {
+                Some(planned_minutes) => format!("{} (planned {} min)", task.name, planned_minutes),
+                None => task.name.to_owned(),
+            }
         } else {
             match self.ui_state.stage {
                 AppStage::Working => {
@@ -324,13 +1047,53 @@ impl App {
             main_top_sections[0],
         );
 
+        if let Some(status_message) = &self.ui_state.status_message {
+            frame.render_widget(
+                Paragraph::new(status_message.to_owned())
+                    .wrap(Wrap { trim: true })
+                    .block(
+                        Block::new()
+                            .borders(Borders::TOP | Borders::RIGHT)
+                            .border_set(symbols::border::THICK)
+                            .title("Status"),
+                    ),
+                main_top_sections[1],
+            );
+        }
+
         match self.ui_state.get_active_tab() {
             Tab::Home => self.ui_state.render_home_tab(frame, main_layouts[1]),
+            Tab::Plan => {
+                let completed_tasks = self.daily_state.completed_tasks.lock().unwrap();
+                let plan_rows = self
+                    .daily_state
+                    .plan
+                    .compare_with_actual(&completed_tasks, current_task.as_ref());
+
+                self.ui_state
+                    .render_plan_tab(frame, main_layouts[1], &plan_rows)
+            }
             Tab::Start => self.ui_state.render_start_tab(frame, main_layouts[1]),
+            Tab::Templates => {
+                self.ui_state
+                    .render_templates_tab(frame, main_layouts[1], &self.templates)
+            }
             Tab::Out => self.ui_state.render_out_tab(frame, main_layouts[1]),
             Tab::End => self.ui_state.render_end_tab(frame, main_layouts[1]),
             Tab::ClearState => self.ui_state.render_clear_tab(frame, main_layouts[1]),
         }
+
+        if let Some(popup) = &self.ui_state.summary_popup {
+            popup.render(frame, area);
+        }
+
+        if let Some(popup) = &self.ui_state.tag_review_popup {
+            popup.render(frame, area);
+        }
+
+        if let Some(prompt) = &self.ui_state.forgotten_task_prompt {
+            prompt.render(frame, area);
+        }
     }
 
     pub fn run(mut self) -> Result<()> {
@@ -344,6 +1107,9 @@ impl App {
 
             // application update
             self.update()?;
+            self.check_forgotten_task();
+            self.update_tag_suggestion();
+            self.show_tag_prediction_errors();
 
             // application exit
             if self.should_quit {
@@ -360,15 +1126,31 @@ impl App {
 
     fn execute_start_command(
         &mut self,
-        new_task_name: String,
+        new_task: NotCompletedTask,
         previous_task_completion_message: Option<String>,
     ) -> Result<String, String> {
         let state = &mut self.daily_state;
         let state_file_path = &self.state_file_path;
 
-        state.complete_current_task(previous_task_completion_message)?;
+        let dt_start = new_task.dt_start;
+
+        if let Some(current_task) = &state.current_task {
+            if current_task.dt_start >= dt_start {
+                return Err("New task can not start before the running one".to_owned());
+            }
+        }
+
+        // Checked before the running task is completed, so a rejected start changes nothing
+        state.check_task_start(dt_start)?;
+
+        state.complete_current_task(
+            previous_task_completion_message,
+            dt_start,
+            &self.issue_key_matcher,
+        )?;
+        state.fit_task_start(dt_start)?;
 
-        let new_task = NotCompletedTask::start(new_task_name.to_string());
+        let new_task_name = new_task.name.to_owned();
 
         state.current_task.replace(new_task);
 
@@ -377,6 +1159,41 @@ impl App {
         Ok(format!("Started new task. Current task: {}", new_task_name))
     }
 
+    fn execute_edit_start_command(&mut self, dt_start: DateTime<Local>) -> Result<String, String> {
+        let state = &mut self.daily_state;
+        let state_file_path = &self.state_file_path;
+
+        state.set_current_task_start(dt_start)?;
+
+        let _ = state.save(state_file_path);
+
+        Ok(format!(
+            "Current task start moved to {}",
+            dt_start.format("%H:%M")
+        ))
+    }
+
+    fn execute_stop_at_command(
+        &mut self,
+        dt_end: DateTime<Local>,
+        task_completion_message: Option<String>,
+    ) -> Result<String, String> {
+        let state = &mut self.daily_state;
+        let state_file_path = &self.state_file_path;
+
+        if let Some(task) = &state.current_task {
+            if dt_end <= task.dt_start || dt_end > Local::now() {
+                return Err("Chosen time is outside of the running task".to_owned());
+            }
+        }
+
+        state.complete_current_task(task_completion_message, dt_end, &self.issue_key_matcher)?;
+
+        let _ = state.save(state_file_path);
+
+        Ok(format!("Task ended at {}", dt_end.format("%H:%M")))
+    }
+
     fn execute_pause_command(
         &mut self,
         previous_task_completion_message: Option<String>,
@@ -384,7 +1201,11 @@ impl App {
         let state = &mut self.daily_state;
         let state_file_path = &self.state_file_path;
 
-        let complete_task_result = state.complete_current_task(previous_task_completion_message);
+        let complete_task_result = state.complete_current_task(
+            previous_task_completion_message,
+            Local::now(),
+            &self.issue_key_matcher,
+        );
 
         state.current_task = None;
 
@@ -395,24 +1216,83 @@ impl App {
         Ok("Track is paused. Out of keyboard".to_string())
     }
 
+    /// Exports the day so far without touching the state, the running task ends at the current time
+    fn execute_export_now_command(&mut self, formats: Vec<String>) -> Result<String, String> {
+        let day = ReportDay::snapshot(&self.daily_state, Local::now(), &self.issue_key_matcher);
+
+        let mut report = Report::snapshot(day);
+        report.assign_predicted_tags(&self.tag_predictor);
+
+        let export_results = export_report(&self.exporters, &formats, &report, &self.output_dir);
+
+        let (file_paths, errors): (Vec<String>, Vec<String>) =
+            export_results.into_iter().partition_result();
+
+        if !errors.is_empty() {
+            return Err(errors.join(". "));
+        }
+
+        Ok(format!("Exported {}", file_paths.join(", ")))
+    }
+
     fn execute_end_command(
         &mut self,
         previous_task_completion_message: Option<String>,
+        formats: Vec<String>,
+        reviewed_tags: HashMap<String, String>,
+        dt_end: DateTime<Local>,
     ) -> Result<String, String> {
         let state = &mut self.daily_state;
         let state_file_path = &self.state_file_path;
 
-        state.complete_current_task(previous_task_completion_message)?;
+        state.complete_current_task(
+            previous_task_completion_message,
+            dt_end,
+            &self.issue_key_matcher,
+        )?;
+
+        // Applied before the prediction below, which sees names with the end comment and could
+        // pick a different tag than the review showed
+        for task in state.completed_tasks.lock().unwrap().iter_mut() {
+            if let Some(tag) = reviewed_tags.get(&task.id()) {
+                task.tag = Some(tag.to_owned());
+            }
+        }
 
-        let save_result = state.save_state_as_xlsx(&self.prediction_model_session);
+        state.end_time = Some(Local::now());
 
-        match save_result {
-            Ok(file_path) => {
-                let _ = state.clear_todays_state(state_file_path);
+        let mut report = Report::from_days(vec![ReportDay::from_daily_state(state)]);
+        report.assign_predicted_tags(&self.tag_predictor);
 
-                Ok(format!("Work is ended. Generated log file {}", file_path))
-            }
-            Err(_) => Err("Could not save workbook".to_string()),
+        let export_results = export_report(&self.exporters, &formats, &report, &self.output_dir);
+
+        let (file_paths, errors): (Vec<String>, Vec<String>) =
+            export_results.into_iter().partition_result();
+
+        let date = state.start_time.format("%Y-%m-%d").to_string();
+
+        // A failed format does not keep the day open: the archived day can be exported again from
+        // the history, while an open day would mix with tomorrow's tasks
+        if let Err(err) = state.clear_todays_state(state_file_path, &self.history) {
+            let _ = state.save(state_file_path);
+
+            return Err(err);
+        }
+
+        let mut message = format!(
+            "Work is ended. Generated log files {}",
+            file_paths.join(", ")
+        );
+
+        if !errors.is_empty() {
+            message = format!(
+                "{}. Failed: {}. Run time-manager export --date {} to retry",
+                message,
+                errors.join(". "),
+                date
+            );
         }
+
+        Ok(message)
     }
 }
@@ -0,0 +1,339 @@
+use std::fs::read;
+use std::path::PathBuf;
+
+use chrono::{prelude::*, Duration, Months};
+use itertools::Itertools;
+
+use crate::{
+    config::Config,
+    export::{export_report, registry, worklog::WorklogEntry},
+    history::History,
+    import::{self, find_importer, ImportPlan},
+    issue::IssueKeyMatcher,
+    paths::AppPaths,
+    report::{Report, ReportDay},
+    state::DailyState,
+    tag_model::TagPredictor,
+    worklog_upload::{SentWorklogs, WorklogUploader},
+};
+
+const CLI_DATE_FORMAT: &str = "%Y-%m-%d";
+
+const USAGE: &str = "Usage:
+  time-manager                           start the interactive tracker
+  time-manager export [options]          export tracked tasks
+  time-manager import [options]          import tasks from other tools into the history
+  time-manager send-worklog [options]    post exported worklog entries to worklog.endpoint
+
+Export options:
+  --format <names>     comma separated formats, defaults to export_formats from config.json
+  --date <yyyy-mm-dd>  archived day to export, by default today so far including the running task
+  --from <yyyy-mm-dd>  first archived day of a range
+  --to <yyyy-mm-dd>    last archived day of a range, today by default
+  --week <yyyy-mm-dd>  archived days of the week (Monday to Sunday) containing the date
+  --month <yyyy-mm>    archived days of the month
+  --output <dir>       directory for the generated files
+
+Import options:
+  --format <name>      timewarrior, org, toggl, clockify, watson or xlsx
+  --input <path>       file to import, can be repeated
+  --date-format <fmt>  date format of toggl and clockify reports, e.g. %d/%m/%Y
+                       (defaults: toggl %Y-%m-%d, clockify %m/%d/%Y)
+  --dry-run            only show what would be imported
+
+Send worklog options:
+  --input <path>       worklog.json file written by the worklog export, can be repeated
+  --endpoint <url>     overrides worklog.endpoint from config.json
+  --dry-run            only show the entries that were not sent yet";
+
+pub fn run(args: &[String], paths: &AppPaths) -> Result<String, String> {
+    match args.first().map(|arg| arg.as_str()) {
+        Some("export") => run_export(&args[1..], paths),
+        Some("import") => run_import(&args[1..], paths),
+        Some("send-worklog") => run_send_worklog(&args[1..], paths),
+        Some("help") | Some("--help") | Some("-h") => Ok(USAGE.to_owned()),
+        _ => Err(USAGE.to_owned()),
+    }
+}
+
+fn run_export(args: &[String], paths: &AppPaths) -> Result<String, String> {
+    let config = Config::init(&paths.config_file)?;
+
+    let formats = match option_value(args, "--format") {
+        Some(formats) => formats
+            .split(',')
+            .map(|format| format.trim().to_owned())
+            .collect(),
+        None => config.export_formats.to_owned(),
+    };
+
+    let output_dir = match option_value(args, "--output") {
+        Some(dir) => PathBuf::from(dir),
+        None => paths.output_dir.to_owned(),
+    };
+
+    let issue_key_matcher = IssueKeyMatcher::init(&config.issue_key_patterns)?;
+
+    let days = load_days(args, paths, &issue_key_matcher)?;
+
+    let is_snapshot = ["--date", "--from", "--to", "--week", "--month"]
+        .iter()
+        .all(|name| option_value(args, name).is_none());
+
+    if days.is_empty() {
+        return Err(if is_snapshot {
+            "No day in progress, pass --date to export an archived one".to_owned()
+        } else {
+            "Nothing to export for the requested dates".to_owned()
+        });
+    }
+
+    let mut report = if is_snapshot {
+        Report::snapshot(days.into_iter().next().unwrap())
+    } else {
+        Report::from_days(days)
+    };
+
+    let tag_predictor = TagPredictor::init(&config.tag_model, &paths.tag_cache_file);
+
+    report.assign_predicted_tags(&tag_predictor);
+
+    let exporters = registry(&config);
+
+    let (file_paths, errors): (Vec<String>, Vec<String>) =
+        export_report(&exporters, &formats, &report, &output_dir)
+            .into_iter()
+            .partition_result();
+
+    // Without the model the files are still written, only the tags may be missing
+    let warnings: String = tag_predictor
+        .load_errors
+        .iter()
+        .cloned()
+        .chain(tag_predictor.take_runtime_errors())
+        .map(|err| format!("\nWarning: {}", err))
+        .collect();
+
+    if !errors.is_empty() {
+        return Err(format!("{}{}", errors.join("\n"), warnings));
+    }
+
+    Ok(format!(
+        "Generated files:\n{}{}",
+        file_paths.join("\n"),
+        warnings
+    ))
+}
+
+fn run_import(args: &[String], paths: &AppPaths) -> Result<String, String> {
+    let importers = import::registry(option_value(args, "--date-format"));
+
+    let format = option_value(args, "--format").ok_or("--format is required".to_owned())?;
+    let importer = find_importer(&importers, format)?;
+    let inputs = option_values(args, "--input");
+
+    if inputs.is_empty() {
+        return Err("--input is required".to_owned());
+    }
+
+    let config = Config::init(&paths.config_file)?;
+    let issue_key_matcher = IssueKeyMatcher::init(&config.issue_key_patterns)?;
+
+    let mut tasks = vec![];
+
+    for input in inputs {
+        let content = read(input).map_err(|_| format!("Could not read {}", input))?;
+
+        let imported_tasks = importer
+            .parse(&content)
+            .map_err(|err| format!("{}: {}", input, err))?;
+
+        tasks.extend(imported_tasks);
+    }
+
+    for task in tasks.iter_mut() {
+        task.issue = issue_key_matcher.find_issue_key(&task.name);
+    }
+
+    let history = History::init(&paths.history_dir)?;
+
+    let import_plan = ImportPlan::prepare(&history, tasks)?;
+    let summary = import_plan.summary();
+
+    if args.iter().any(|arg| arg == "--dry-run") {
+        return Ok(format!("Dry run, nothing is written\n{}", summary));
+    }
+
+    let result = import_plan.apply(&history)?;
+
+    Ok(format!("{}\n{}", summary, result))
+}
+
+fn run_send_worklog(args: &[String], paths: &AppPaths) -> Result<String, String> {
+    let inputs = option_values(args, "--input");
+
+    if inputs.is_empty() {
+        return Err("--input is required".to_owned());
+    }
+
+    let mut config = Config::init(&paths.config_file)?;
+
+    if let Some(endpoint) = option_value(args, "--endpoint") {
+        config.worklog.endpoint = Some(endpoint.to_owned());
+    }
+
+    let uploader = WorklogUploader::from_config(&config.worklog)?;
+    let mut sent_worklogs = SentWorklogs::init(&paths.sent_worklogs_file)?;
+
+    let mut entries: Vec<WorklogEntry> = vec![];
+
+    for input in inputs {
+        let content = read(input).map_err(|_| format!("Could not read {}", input))?;
+
+        let file_entries: Vec<WorklogEntry> = serde_json::from_slice(&content)
+            .map_err(|_| format!("{}: not a worklog JSON file", input))?;
+
+        entries.extend(file_entries);
+    }
+
+    let entries_count = entries.len();
+
+    let (pending_entries, conflicts) = sent_worklogs.pending(entries);
+    let has_conflicts = !conflicts.is_empty();
+
+    let mut lines = vec![format!(
+        "{} entries to send, {} already sent or repeated",
+        pending_entries.len(),
+        entries_count - pending_entries.len()
+    )];
+
+    lines.extend(conflicts);
+
+    if args.iter().any(|arg| arg == "--dry-run") {
+        lines.extend(pending_entries.iter().map(|entry| {
+            format!(
+                "  {} {} {}s -> {}",
+                entry.issue_key,
+                entry.started,
+                entry.time_spent_seconds,
+                uploader.url(entry)
+            )
+        }));
+
+        return Ok(lines.join("\n"));
+    }
+
+    let mut errors = vec![];
+
+    for entry in &pending_entries {
+        match uploader.send(entry) {
+            Ok(_) => {
+                // Saved after every entry, so a failure later on does not lose what was sent
+                sent_worklogs.record(entry);
+                sent_worklogs.save(&paths.sent_worklogs_file)?;
+            }
+            Err(err) => errors.push(err),
+        }
+    }
+
+    lines.push(format!(
+        "Sent {} of {} entries",
+        pending_entries.len() - errors.len(),
+        pending_entries.len()
+    ));
+
+    if errors.is_empty() && !has_conflicts {
+        Ok(lines.join("\n"))
+    } else {
+        lines.extend(errors);
+
+        Err(lines.join("\n"))
+    }
+}
+
+fn load_days(
+    args: &[String],
+    paths: &AppPaths,
+    issue_key_matcher: &IssueKeyMatcher,
+) -> Result<Vec<ReportDay>, String> {
+    let date = parse_date_option(args, "--date")?;
+    let from = parse_date_option(args, "--from")?;
+    let to = parse_date_option(args, "--to")?;
+    let week = parse_date_option(args, "--week")?;
+    let month = parse_month_option(args)?;
+
+    let (from, to) = match (week, month) {
+        (Some(day_of_week), _) => {
+            let monday = day_of_week.week(Weekday::Mon).first_day();
+
+            (Some(monday), Some(monday + Duration::days(6)))
+        }
+        (None, Some(first_day)) => {
+            let last_day = first_day
+                .checked_add_months(Months::new(1))
+                .and_then(|next_month| next_month.pred_opt())
+                .unwrap_or(first_day);
+
+            (Some(first_day), Some(last_day))
+        }
+        (None, None) => (from, to),
+    };
+
+    if date.is_none() && from.is_none() && to.is_none() {
+        // An export only reads the state, the tracker owns the file
+        let state = DailyState::read(&paths.state_file)?;
+
+        let day = state
+            .map(|state| ReportDay::snapshot(&state, Local::now(), issue_key_matcher))
+            .filter(|day| !day.tasks.is_empty());
+
+        return Ok(day.into_iter().collect());
+    }
+
+    let history = History::init(&paths.history_dir)?;
+
+    match (date, from) {
+        (Some(date), _) => Ok(history.load_day(date)?.into_iter().collect()),
+        (None, Some(from)) => {
+            let to = to.unwrap_or(Local::now().date_naive());
+
+            history.load_range(from, to)
+        }
+        (None, None) => Err("--to requires --from".to_owned()),
+    }
+}
+
+fn parse_date_option(args: &[String], name: &str) -> Result<Option<NaiveDate>, String> {
+    match option_value(args, name) {
+        Some(value) => match NaiveDate::parse_from_str(value, CLI_DATE_FORMAT) {
+            Ok(date) => Ok(Some(date)),
+            Err(_) => Err(format!("{} expects a date like 2026-10-18", name)),
+        },
+        None => Ok(None),
+    }
+}
+
+fn parse_month_option(args: &[String]) -> Result<Option<NaiveDate>, String> {
+    match option_value(args, "--month") {
+        Some(value) => match NaiveDate::parse_from_str(&format!("{}-01", value), CLI_DATE_FORMAT) {
+            Ok(date) => Ok(Some(date)),
+            Err(_) => Err("--month expects a month like 2026-10".to_owned()),
+        },
+        None => Ok(None),
+    }
+}
+
+fn option_values<'a>(args: &'a [String], name: &str) -> Vec<&'a str> {
+    args.iter()
+        .tuple_windows()
+        .filter(|(arg, _)| *arg == name)
+        .map(|(_, value)| value.as_str())
+        .collect()
+}
+
+fn option_value<'a>(args: &'a [String], name: &str) -> Option<&'a str> {
+    args.iter()
+        .position(|arg| arg == name)
+        .and_then(|idx| args.get(idx + 1))
+        .map(|value| value.as_str())
+}
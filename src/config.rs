@@ -0,0 +1,353 @@
+use std::fs::File;
+use std::io::prelude::*;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    pub issue_key_patterns: Vec<String>,
+    pub export_formats: Vec<String>,
+    pub worklog: WorklogConfig,
+    pub csv: CsvConfig,
+    pub xlsx: XlsxConfig,
+    pub timewarrior: TimewarriorConfig,
+    pub timeclock: TimeclockConfig,
+    pub email: EmailConfig,
+    pub tag_model: TagModelConfig,
+    pub tag_review: TagReviewConfig,
+    pub forgotten_task: ForgottenTaskConfig,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WorklogConfig {
+    pub format: WorklogFormat,
+    /// Where `send-worklog` posts the entries, `{issueKey}` is replaced with the issue key
+    pub endpoint: Option<String>,
+    /// Value of the Authorization header, e.g. `Bearer <token>`
+    pub authorization: Option<String>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CsvConfig {
+    pub delimiter: char,
+}
+
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TimewarriorConfig {
+    /// Timewarrior data directory, e.g. `~/.timewarrior/data`, the output directory by default
+    pub data_dir: Option<PathBuf>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TimeclockConfig {
+    pub account_prefix: String,
+    pub account_from: TimeclockAccountSource,
+}
+
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TimeclockAccountSource {
+    Tag,
+    Project,
+}
+
+/// Draft email with the xlsx report attached, written as an `.eml` file
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EmailConfig {
+    pub to: String,
+    pub from: Option<String>,
+    /// `{date}` and `{hours}` are replaced with the report dates and the total hours
+    pub subject: String,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TagModelConfig {
+    /// Without the model only the rules assign tags
+    pub enabled: bool,
+    /// ONNX model file, the model embedded into the binary by default
+    pub path: Option<PathBuf>,
+    pub rules: Vec<TagRule>,
+    /// Tags offered instead of the predicted one, the labels of the embedded model by default
+    pub known_tags: Vec<String>,
+}
+
+/// Review of the day's tags before End writes the report
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TagReviewConfig {
+    pub enabled: bool,
+    /// Changed tags are appended to `tag_corrections.csv` in the model training data format
+    pub save_corrections: bool,
+}
+
+/// Tasks with a name matching the pattern get the tag, rules win over the model
+#[derive(Clone, Serialize, Deserialize)]
+pub struct TagRule {
+    pub pattern: String,
+    pub tag: String,
+}
+
+/// Layout of the day sheets: the title row with the date, an optional header row, then tasks
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct XlsxConfig {
+    pub font_name: String,
+    pub font_size: f64,
+    pub background_color: String,
+    pub row_height: f64,
+    pub header_row: bool,
+    pub freeze_panes: bool,
+    pub autofilter: bool,
+    pub columns: Vec<XlsxColumn>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct XlsxColumn {
+    pub field: XlsxField,
+    #[serde(default)]
+    pub header: String,
+    #[serde(default)]
+    pub width: Option<f64>,
+    #[serde(default)]
+    pub num_format: Option<String>,
+    #[serde(default)]
+    pub bold: bool,
+}
+
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum XlsxField {
+    Date,
+    Tag,
+    Name,
+    Comment,
+    Project,
+    Issue,
+    Start,
+    End,
+    Duration,
+    Hours,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ForgottenTaskConfig {
+    pub enabled: bool,
+    pub max_hours: f64,
+    pub idle_minutes: u32,
+}
+
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WorklogFormat {
+    Json,
+    Csv,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            issue_key_patterns: vec![
+                // Jira and YouTrack: PROJ-123
+                r"\b[A-Z][A-Z0-9_]+-\d+\b".to_owned(),
+                // GitHub: owner/repo#123
+                r"\b[\w.-]+/[\w.-]+#\d+\b".to_owned(),
+                // GitHub: #123
+                r"#\d+\b".to_owned(),
+            ],
+            export_formats: vec!["xlsx".to_owned(), "worklog".to_owned()],
+            worklog: WorklogConfig::default(),
+            csv: CsvConfig::default(),
+            xlsx: XlsxConfig::default(),
+            timewarrior: TimewarriorConfig::default(),
+            timeclock: TimeclockConfig::default(),
+            email: EmailConfig::default(),
+            tag_model: TagModelConfig::default(),
+            tag_review: TagReviewConfig::default(),
+            forgotten_task: ForgottenTaskConfig::default(),
+        }
+    }
+}
+
+impl Default for WorklogConfig {
+    fn default() -> Self {
+        WorklogConfig {
+            format: WorklogFormat::Json,
+            endpoint: None,
+            authorization: None,
+        }
+    }
+}
+
+impl Default for CsvConfig {
+    fn default() -> Self {
+        // Same separator as the model training data in model/best_model.ipynb
+        CsvConfig { delimiter: ';' }
+    }
+}
+
+impl Default for TimeclockConfig {
+    fn default() -> Self {
+        TimeclockConfig {
+            account_prefix: "work".to_owned(),
+            account_from: TimeclockAccountSource::Project,
+        }
+    }
+}
+
+impl Default for EmailConfig {
+    fn default() -> Self {
+        EmailConfig {
+            to: String::new(),
+            from: None,
+            subject: "Time report {date}".to_owned(),
+        }
+    }
+}
+
+impl Default for TagModelConfig {
+    fn default() -> Self {
+        TagModelConfig {
+            enabled: true,
+            path: None,
+            rules: vec![],
+            known_tags: [
+                "General-внедрение",
+                "General-внедрения",
+                "General-развитие",
+                "General-сопровождение",
+                "HoReCa-Tr-внедрение",
+                "HoReCa-Tr-развитие",
+                "HoReCa-Tr-сопровождение",
+                "HoReCa-внедрение",
+                "HoReCa-развитие",
+                "HoReCa-сопровождение",
+                "Внепроектное",
+                "Координация",
+            ]
+            .iter()
+            .map(|tag| tag.to_string())
+            .collect(),
+        }
+    }
+}
+
+impl Default for TagReviewConfig {
+    fn default() -> Self {
+        TagReviewConfig {
+            enabled: true,
+            save_corrections: false,
+        }
+    }
+}
+
+impl Default for XlsxConfig {
+    fn default() -> Self {
+        let column = |field, header: &str, width, num_format: Option<&str>, bold| XlsxColumn {
+            field,
+            header: header.to_owned(),
+            width,
+            num_format: num_format.map(|num_format| num_format.to_owned()),
+            bold,
+        };
+
+        XlsxConfig {
+            font_name: "Nunito".to_owned(),
+            font_size: 10_f64,
+            background_color: "#EEEEEE".to_owned(),
+            row_height: 50_f64,
+            header_row: true,
+            freeze_panes: true,
+            autofilter: true,
+            columns: vec![
+                column(XlsxField::Date, "Date", Some(15_f64), None, false),
+                column(XlsxField::Tag, "Tag", Some(25_f64), None, false),
+                column(XlsxField::Name, "Task", Some(40_f64), None, false),
+                column(XlsxField::Start, "Start", None, Some("hh:mm"), false),
+                column(XlsxField::End, "End", None, Some("hh:mm"), false),
+                column(XlsxField::Duration, "Duration", None, Some("hh:mm"), false),
+                column(XlsxField::Hours, "Hours", None, Some("0.00"), true),
+                column(XlsxField::Project, "Project", Some(20_f64), None, false),
+            ],
+        }
+    }
+}
+
+impl XlsxConfig {
+    pub fn column_idx(&self, field: XlsxField) -> Option<u16> {
+        self.columns
+            .iter()
+            .position(|column| column.field == field)
+            .map(|idx| idx.try_into().unwrap())
+    }
+
+    /// Row index of the first task, counted from 0
+    pub fn first_task_row(&self) -> u32 {
+        if self.header_row {
+            2
+        } else {
+            1
+        }
+    }
+}
+
+impl Default for ForgottenTaskConfig {
+    fn default() -> Self {
+        ForgottenTaskConfig {
+            enabled: true,
+            max_hours: 4_f64,
+            idle_minutes: 30,
+        }
+    }
+}
+
+impl Config {
+    pub fn init(file_path: &PathBuf) -> Result<Config, String> {
+        let result_with_opened_file = File::open(file_path);
+
+        match result_with_opened_file {
+            Ok(mut file) => {
+                let mut string = String::new();
+                let read_result = file.read_to_string(&mut string);
+
+                match read_result {
+                    Ok(_) => match serde_json::from_str(&string) {
+                        Ok(config) => Ok(config),
+                        Err(_) => Err("Config file is not valid JSON".to_owned()),
+                    },
+                    Err(_) => Err("Could not read config file".to_owned()),
+                }
+            }
+            Err(_) => {
+                let config = Config::default();
+
+                let _ = config.save(file_path);
+
+                Ok(config)
+            }
+        }
+    }
+
+    pub fn save(&self, file_path: &PathBuf) -> Result<String, String> {
+        let result_with_string = serde_json::to_string_pretty(self);
+
+        match result_with_string {
+            Ok(string) => match File::create(file_path) {
+                Ok(mut file) => match file.write_all(string.as_bytes()) {
+                    Ok(_) => Ok("Config saved".to_owned()),
+                    Err(_) => Err("Could not write config to file".to_owned()),
+                },
+                Err(_) => Err("Could not create config file".to_owned()),
+            },
+            Err(_) => Err("Could not save config as JSON".to_owned()),
+        }
+    }
+}
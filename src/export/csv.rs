@@ -0,0 +1,70 @@
+use std::fs::File;
+use std::io::prelude::*;
+use std::path::Path;
+
+use crate::{report::Report, utils::escape_csv_field};
+
+use super::{Exporter, TaskRow};
+
+pub struct CsvExporter {
+    pub delimiter: char,
+}
+
+const HEADERS: [&str; 8] = [
+    "ID", "Name", "Comment", "Tag", "Project", "Start", "End", "Duration",
+];
+
+impl Exporter for CsvExporter {
+    fn name(&self) -> &'static str {
+        "csv"
+    }
+
+    fn export(&self, report: &Report, output_dir: &Path) -> Result<String, String> {
+        let delimiter = self.delimiter.to_string();
+
+        let mut lines = vec![HEADERS.join(&delimiter)];
+
+        for task in report.tasks() {
+            let row = TaskRow::from_task(task);
+
+            let duration_seconds = row.duration_seconds.max(0);
+            let duration = format!(
+                "{:02}:{:02}:{:02}",
+                duration_seconds / 3600,
+                duration_seconds % 3600 / 60,
+                duration_seconds % 60
+            );
+
+            let fields = [
+                row.id,
+                row.name,
+                row.comment.unwrap_or_default(),
+                row.tag.unwrap_or_default(),
+                row.project.unwrap_or_default(),
+                row.start,
+                row.end,
+                duration,
+            ];
+
+            lines.push(
+                fields
+                    .iter()
+                    .map(|field| escape_csv_field(field, self.delimiter))
+                    .collect::<Vec<String>>()
+                    .join(&delimiter),
+            );
+        }
+
+        let content = lines.join("\r\n") + "\r\n";
+
+        let file_path = output_dir.join(format!("{}.csv", report.file_stem()));
+
+        let write_result =
+            File::create(&file_path).and_then(|mut file| file.write_all(content.as_bytes()));
+
+        match write_result {
+            Ok(_) => Ok(file_path.to_string_lossy().to_string()),
+            Err(_) => Err("Could not write CSV file".to_owned()),
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use std::fs::File;
+use std::io::prelude::*;
+use std::path::Path;
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use chrono::prelude::*;
+
+use crate::{
+    config::{EmailConfig, XlsxConfig},
+    report::Report,
+    summary::Summary,
+};
+
+use super::{xlsx::XlsxExporter, Exporter};
+
+const XLSX_CONTENT_TYPE: &str = "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet";
+const BASE64_LINE_LENGTH: usize = 76;
+/// Bytes of text in one RFC 2047 encoded word: 39 bytes are 52 base64 characters, so the word
+/// is 64 characters long and `Subject: ` with the first word stays within 76
+const ENCODED_WORD_TEXT_LENGTH: usize = 39;
+
+/// Unsent email with a plain text summary and the xlsx report attached,
+/// mail clients open it as a draft so nothing is sent from here
+pub struct EmlExporter {
+    pub email: EmailConfig,
+    pub layout: XlsxConfig,
+}
+
+impl Exporter for EmlExporter {
+    fn name(&self) -> &'static str {
+        "eml"
+    }
+
+    fn export(&self, report: &Report, output_dir: &Path) -> Result<String, String> {
+        let xlsx_exporter = XlsxExporter {
+            layout: self.layout.clone(),
+        };
+
+        let attachment = xlsx_exporter
+            .workbook(report)
+            .save_to_buffer()
+            .map_err(|_| "Could not save workbook for the email".to_owned())?;
+
+        let attachment_name = format!("{}.xlsx", report.file_stem());
+        let boundary = format!("time-manager-{}", Local::now().timestamp());
+
+        let subject = self
+            .email
+            .subject
+            .replace("{date}", &report.file_stem())
+            .replace("{hours}", &format!("{:.2}", report.total_hours()));
+
+        let body = report
+            .days
+            .iter()
+            .map(|day| Summary::from_day(day).to_plain_text())
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        let mut lines = vec![];
+
+        if let Some(from) = &self.email.from {
+            lines.push(format!("From: {}", from));
+        }
+
+        if !self.email.to.is_empty() {
+            lines.push(format!("To: {}", self.email.to));
+        }
+
+        lines.extend([
+            format!("Subject: {}", encode_header(&subject)),
+            format!("Date: {}", Local::now().to_rfc2822()),
+            "MIME-Version: 1.0".to_owned(),
+            // Outlook opens the file as a new message instead of a received one
+            "X-Unsent: 1".to_owned(),
+            format!("Content-Type: multipart/mixed; boundary=\"{}\"", boundary),
+            String::new(),
+            format!("--{}", boundary),
+            "Content-Type: text/plain; charset=UTF-8".to_owned(),
+            "Content-Transfer-Encoding: base64".to_owned(),
+            String::new(),
+        ]);
+
+        lines.extend(encode_base64_lines(body.as_bytes()));
+
+        lines.extend([
+            format!("--{}", boundary),
+            format!(
+                "Content-Type: {}; name=\"{}\"",
+                XLSX_CONTENT_TYPE, attachment_name
+            ),
+            format!(
+                "Content-Disposition: attachment; filename=\"{}\"",
+                attachment_name
+            ),
+            "Content-Transfer-Encoding: base64".to_owned(),
+            String::new(),
+        ]);
+
+        lines.extend(encode_base64_lines(&attachment));
+
+        lines.push(format!("--{}--", boundary));
+
+        let content: String = lines.iter().map(|line| format!("{}\r\n", line)).collect();
+
+        let file_path = output_dir.join(format!("{}.eml", report.file_stem()));
+
+        let write_result =
+            File::create(&file_path).and_then(|mut file| file.write_all(content.as_bytes()));
+
+        match write_result {
+            Ok(_) => Ok(file_path.to_string_lossy().to_string()),
+            Err(_) => Err("Could not write email file".to_owned()),
+        }
+    }
+}
+
+/// Non-ASCII header values are written as RFC 2047 encoded words. An encoded word may not be
+/// longer than 75 characters, so the value is split into several words folded onto
+/// continuation lines. Words are split between characters, never inside a UTF-8 sequence
+fn encode_header(value: &str) -> String {
+    if value.is_ascii() {
+        return value.to_owned();
+    }
+
+    let mut words = vec![];
+    let mut word_start = 0;
+    let mut word_end = 0;
+
+    for (idx, char) in value.char_indices() {
+        if idx + char.len_utf8() - word_start > ENCODED_WORD_TEXT_LENGTH {
+            words.push(&value[word_start..word_end]);
+            word_start = word_end;
+        }
+
+        word_end = idx + char.len_utf8();
+    }
+
+    words.push(&value[word_start..]);
+
+    words
+        .iter()
+        .map(|word| format!("=?UTF-8?B?{}?=", STANDARD.encode(word)))
+        .collect::<Vec<String>>()
+        .join("\r\n ")
+}
+
+fn encode_base64_lines(content: &[u8]) -> Vec<String> {
+    STANDARD
+        .encode(content)
+        .as_bytes()
+        .chunks(BASE64_LINE_LENGTH)
+        .map(|chunk| String::from_utf8_lossy(chunk).to_string())
+        .collect()
+}
@@ -0,0 +1,287 @@
+use std::fs::File;
+use std::io::prelude::*;
+use std::path::Path;
+
+use chrono::{prelude::*, Duration};
+
+use crate::{
+    report::{Report, ReportDay, NO_TAG},
+    summary::format_duration,
+    task::CompletedTask,
+};
+
+use super::Exporter;
+
+const TAG_COLORS: [&str; 12] = [
+    "#4e79a7", "#f28e2b", "#59a14f", "#e15759", "#76b7b2", "#edc948", "#b07aa1", "#ff9da7",
+    "#9c755f", "#86bcb6", "#d37295", "#a0cbe8",
+];
+
+const STYLE: &str = r#"
+body { font-family: -apple-system, "Segoe UI", Roboto, sans-serif; margin: 0 auto; max-width: 960px; padding: 16px; color: #222; background: #fafafa; }
+h1 { font-size: 1.4em; }
+h2 { font-size: 1.15em; margin-top: 2em; }
+.total { color: #666; font-weight: normal; }
+.tags { list-style: none; padding: 0; }
+.tags li { display: flex; align-items: center; gap: 8px; padding: 4px; cursor: pointer; border-radius: 4px; }
+.tags li:hover, .tags li.selected { background: #eee; }
+.swatch { width: 12px; height: 12px; border-radius: 2px; flex: none; }
+.tag-name { flex: 1; }
+.tag-bar { height: 8px; border-radius: 4px; }
+.timeline { position: relative; height: 32px; background: #e8e8e8; border-radius: 4px; overflow: hidden; }
+.segment { position: absolute; top: 0; bottom: 0; min-width: 2px; cursor: pointer; border-right: 1px solid #fafafa; }
+.hours { position: relative; height: 16px; font-size: 0.75em; color: #888; }
+.hours span { position: absolute; transform: translateX(-50%); }
+table { border-collapse: collapse; width: 100%; margin-top: 8px; font-size: 0.9em; }
+th, td { text-align: left; padding: 6px; border-bottom: 1px solid #ddd; vertical-align: top; }
+td.number { text-align: right; white-space: nowrap; }
+tr.highlighted { background: #fff3c4; }
+.dimmed { opacity: 0.2; }
+#tooltip { position: fixed; display: none; padding: 6px 8px; background: #222; color: #fff; font-size: 0.8em; border-radius: 4px; pointer-events: none; max-width: 300px; }
+@media (max-width: 600px) { .comment, .project { display: none; } }
+"#;
+
+/// Hovering a timeline segment shows the task, clicking it highlights its row,
+/// clicking a tag total dims the tasks of the other tags
+const SCRIPT: &str = r#"
+const tooltip = document.getElementById("tooltip");
+document.querySelectorAll(".segment").forEach((segment) => {
+  segment.addEventListener("mousemove", (event) => {
+    tooltip.textContent = segment.dataset.title;
+    tooltip.style.display = "block";
+    tooltip.style.left = event.clientX + 12 + "px";
+    tooltip.style.top = event.clientY + 12 + "px";
+  });
+  segment.addEventListener("mouseleave", () => { tooltip.style.display = "none"; });
+  segment.addEventListener("click", () => {
+    document.querySelectorAll("tr.highlighted").forEach((row) => row.classList.remove("highlighted"));
+    const row = document.getElementById("task-" + segment.dataset.task);
+    row.classList.add("highlighted");
+    row.scrollIntoView({ behavior: "smooth", block: "center" });
+  });
+});
+let selectedTag = null;
+document.querySelectorAll(".tags li").forEach((item) => {
+  item.addEventListener("click", () => {
+    selectedTag = selectedTag === item.dataset.tag ? null : item.dataset.tag;
+    document.querySelectorAll(".tags li").forEach((other) => other.classList.toggle("selected", other.dataset.tag === selectedTag));
+    document.querySelectorAll("[data-tag]").forEach((element) => {
+      if (element.tagName !== "LI") {
+        element.classList.toggle("dimmed", selectedTag !== null && element.dataset.tag !== selectedTag);
+      }
+    });
+  });
+});
+"#;
+
+/// Single offline page with inline styles and script: per-tag totals of the
+/// report, then a timeline bar and a table of tasks for every day
+pub struct HtmlExporter;
+
+impl Exporter for HtmlExporter {
+    fn name(&self) -> &'static str {
+        "html"
+    }
+
+    fn export(&self, report: &Report, output_dir: &Path) -> Result<String, String> {
+        let title = format!("Time report {}", report.file_stem());
+        let tags: Vec<String> = report
+            .hours_by_tag()
+            .into_iter()
+            .map(|(tag, _)| tag)
+            .collect();
+
+        let mut lines = vec![
+            "<!DOCTYPE html>".to_owned(),
+            "<html>".to_owned(),
+            "<head>".to_owned(),
+            "<meta charset=\"utf-8\">".to_owned(),
+            "<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">".to_owned(),
+            format!("<title>{}</title>", escape_html(&title)),
+            format!("<style>{}</style>", STYLE),
+            "</head>".to_owned(),
+            "<body>".to_owned(),
+            format!(
+                "<h1>{} <span class=\"total\">{}</span></h1>",
+                escape_html(&title),
+                format_hours(report.total_hours())
+            ),
+        ];
+
+        lines.extend(tag_totals(report, &tags));
+
+        for day in &report.days {
+            lines.extend(day_section(day, &tags));
+        }
+
+        lines.extend([
+            "<div id=\"tooltip\"></div>".to_owned(),
+            format!("<script>{}</script>", SCRIPT),
+            "</body>".to_owned(),
+            "</html>".to_owned(),
+        ]);
+
+        let content = lines.join("\n");
+
+        let file_path = output_dir.join(format!("{}.html", report.file_stem()));
+
+        let write_result =
+            File::create(&file_path).and_then(|mut file| file.write_all(content.as_bytes()));
+
+        match write_result {
+            Ok(_) => Ok(file_path.to_string_lossy().to_string()),
+            Err(_) => Err("Could not write HTML report".to_owned()),
+        }
+    }
+}
+
+fn tag_totals(report: &Report, tags: &[String]) -> Vec<String> {
+    let hours_by_tag = report.hours_by_tag();
+    let max_hours = hours_by_tag
+        .iter()
+        .map(|(_, hours)| *hours)
+        .fold(0_f64, f64::max);
+
+    let mut lines = vec!["<ul class=\"tags\">".to_owned()];
+
+    lines.extend(hours_by_tag.iter().map(|(tag, hours)| {
+        let width = if max_hours > 0_f64 {
+            hours / max_hours * 50_f64
+        } else {
+            0_f64
+        };
+
+        format!(
+            "<li data-tag=\"{tag}\"><span class=\"swatch\" style=\"background: {color}\"></span><span class=\"tag-name\">{tag}</span><span class=\"tag-bar\" style=\"width: {width:.1}%; background: {color}\"></span><span>{hours}</span></li>",
+            tag = escape_html(tag),
+            color = tag_color(tags, tag),
+            width = width,
+            hours = format_hours(*hours)
+        )
+    }));
+
+    lines.push("</ul>".to_owned());
+
+    lines
+}
+
+fn day_section(day: &ReportDay, tags: &[String]) -> Vec<String> {
+    let mut lines = vec![format!(
+        "<h2>{} <span class=\"total\">{}</span></h2>",
+        day.date.format("%d.%m.%Y, %A"),
+        format_hours(day.total_hours())
+    )];
+
+    let timeline_start = day.tasks.iter().map(|task| task.dt_start).min();
+    let timeline_end = day.tasks.iter().map(|task| task.dt_end).max();
+
+    if let (Some(timeline_start), Some(timeline_end)) = (timeline_start, timeline_end) {
+        lines.extend(timeline(day, tags, timeline_start, timeline_end));
+    }
+
+    lines.extend([
+        "<table>".to_owned(),
+        "<tr><th>Start</th><th>End</th><th>Duration</th><th>Tag</th><th>Task</th><th class=\"comment\">Comment</th><th class=\"project\">Project</th></tr>".to_owned(),
+    ]);
+
+    lines.extend(day.tasks.iter().map(|task| {
+        format!(
+            "<tr id=\"task-{id}\" data-tag=\"{tag}\"><td class=\"number\">{start}</td><td class=\"number\">{end}</td><td class=\"number\">{duration}</td><td><span class=\"swatch\" style=\"display: inline-block; background: {color}\"></span> {tag}</td><td>{name}</td><td class=\"comment\">{comment}</td><td class=\"project\">{project}</td></tr>",
+            id = task.id(),
+            tag = escape_html(&task_tag(task)),
+            start = task.dt_start.format("%H:%M"),
+            end = task.dt_end.format("%H:%M"),
+            duration = format_duration(task.duration()),
+            color = tag_color(tags, &task_tag(task)),
+            name = escape_html(&task.name_without_comment()),
+            comment = escape_html(task.end_comment.as_deref().unwrap_or_default()),
+            project = escape_html(task.project.as_deref().unwrap_or_default())
+        )
+    }));
+
+    lines.push("</table>".to_owned());
+
+    lines
+}
+
+/// Segments are placed in percent of the time between the first start and the last end of the day
+fn timeline(
+    day: &ReportDay,
+    tags: &[String],
+    timeline_start: DateTime<Local>,
+    timeline_end: DateTime<Local>,
+) -> Vec<String> {
+    let timeline_seconds = (timeline_end - timeline_start).num_seconds().max(1) as f64;
+    let percent = |dt: DateTime<Local>| {
+        (dt - timeline_start).num_seconds() as f64 / timeline_seconds * 100_f64
+    };
+
+    let mut lines = vec!["<div class=\"timeline\">".to_owned()];
+
+    lines.extend(day.tasks.iter().map(|task| {
+        let title = format!(
+            "{} - {} ({}) {}",
+            task.dt_start.format("%H:%M"),
+            task.dt_end.format("%H:%M"),
+            format_duration(task.duration()),
+            task.name
+        );
+
+        format!(
+            "<div class=\"segment\" data-task=\"{id}\" data-tag=\"{tag}\" data-title=\"{title}\" style=\"left: {left:.3}%; width: {width:.3}%; background: {color}\"></div>",
+            id = task.id(),
+            tag = escape_html(&task_tag(task)),
+            title = escape_html(&title),
+            left = percent(task.dt_start),
+            width = percent(task.dt_end) - percent(task.dt_start),
+            color = tag_color(tags, &task_tag(task))
+        )
+    }));
+
+    lines.extend(["</div>".to_owned(), "<div class=\"hours\">".to_owned()]);
+
+    // A label for every full hour inside the timeline
+    let mut hour = timeline_start
+        .with_minute(0)
+        .and_then(|dt| dt.with_second(0))
+        .and_then(|dt| dt.with_nanosecond(0))
+        .unwrap_or(timeline_start);
+
+    while hour <= timeline_end {
+        if hour >= timeline_start {
+            lines.push(format!(
+                "<span style=\"left: {:.3}%\">{}</span>",
+                percent(hour),
+                hour.format("%H")
+            ));
+        }
+
+        hour += Duration::hours(1);
+    }
+
+    lines.push("</div>".to_owned());
+
+    lines
+}
+
+fn task_tag(task: &CompletedTask) -> String {
+    task.tag.to_owned().unwrap_or(NO_TAG.to_owned())
+}
+
+fn tag_color(tags: &[String], tag: &str) -> &'static str {
+    let idx = tags.iter().position(|known| known == tag).unwrap_or(0);
+
+    TAG_COLORS[idx % TAG_COLORS.len()]
+}
+
+fn format_hours(hours: f64) -> String {
+    format!("{:.2} h", hours)
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
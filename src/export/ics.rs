@@ -0,0 +1,104 @@
+use std::fs::File;
+use std::io::prelude::*;
+use std::path::Path;
+
+use chrono::prelude::*;
+
+use crate::{report::Report, task::CompletedTask};
+
+use super::Exporter;
+
+const ICS_DATE_TIME_FORMAT: &str = "%Y%m%dT%H%M%SZ";
+const ICS_MAX_LINE_LENGTH: usize = 75;
+
+/// iCalendar file with one event per completed task
+pub struct IcsExporter;
+
+impl Exporter for IcsExporter {
+    fn name(&self) -> &'static str {
+        "ics"
+    }
+
+    fn export(&self, report: &Report, output_dir: &Path) -> Result<String, String> {
+        let mut lines = vec![
+            "BEGIN:VCALENDAR".to_owned(),
+            "VERSION:2.0".to_owned(),
+            "PRODID:-//time-manager//EN".to_owned(),
+            "CALSCALE:GREGORIAN".to_owned(),
+        ];
+
+        for task in report.tasks() {
+            lines.extend(task_as_event(task));
+        }
+
+        lines.push("END:VCALENDAR".to_owned());
+
+        let content: String = lines.iter().map(|line| fold_line(line) + "\r\n").collect();
+
+        let file_path = output_dir.join(format!("{}.ics", report.file_stem()));
+
+        let write_result =
+            File::create(&file_path).and_then(|mut file| file.write_all(content.as_bytes()));
+
+        match write_result {
+            Ok(_) => Ok(file_path.to_string_lossy().to_string()),
+            Err(_) => Err("Could not write calendar file".to_owned()),
+        }
+    }
+}
+
+/// Times are written in UTC, so calendars show them in their own time zone
+fn task_as_event(task: &CompletedTask) -> Vec<String> {
+    let mut lines = vec![
+        "BEGIN:VEVENT".to_owned(),
+        format!("UID:{}@time-manager", task.id()),
+        format!("DTSTAMP:{}", format_ics_date_time(&task.dt_end)),
+        format!("DTSTART:{}", format_ics_date_time(&task.dt_start)),
+        format!("DTEND:{}", format_ics_date_time(&task.dt_end)),
+        format!("SUMMARY:{}", escape_ics_text(&task.name_without_comment())),
+    ];
+
+    if let Some(tag) = &task.tag {
+        lines.push(format!("CATEGORIES:{}", escape_ics_text(tag)));
+    }
+
+    if let Some(comment) = &task.end_comment {
+        lines.push(format!("DESCRIPTION:{}", escape_ics_text(comment)));
+    }
+
+    lines.push("END:VEVENT".to_owned());
+
+    lines
+}
+
+fn format_ics_date_time(dt: &DateTime<Local>) -> String {
+    dt.with_timezone(&Utc)
+        .format(ICS_DATE_TIME_FORMAT)
+        .to_string()
+}
+
+fn escape_ics_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+        .replace('\r', "")
+}
+
+/// Long lines are split into 75 octet chunks, continuation lines start with a space
+fn fold_line(line: &str) -> String {
+    let mut folded = String::new();
+    let mut line_length = 0;
+
+    for char in line.chars() {
+        if line_length + char.len_utf8() > ICS_MAX_LINE_LENGTH {
+            folded.push_str("\r\n ");
+            line_length = 1;
+        }
+
+        folded.push(char);
+        line_length += char.len_utf8();
+    }
+
+    folded
+}
@@ -0,0 +1,120 @@
+pub mod csv;
+pub mod eml;
+pub mod html;
+pub mod ics;
+pub mod ndjson;
+pub mod org;
+pub mod summary;
+pub mod timeclock;
+pub mod timesheet;
+pub mod timewarrior;
+pub mod worklog;
+pub mod xlsx;
+
+use std::path::Path;
+
+use serde::Serialize;
+
+use crate::{config::Config, report::Report, summary::SummaryFormat, task::CompletedTask};
+
+pub use self::{
+    csv::CsvExporter, eml::EmlExporter, html::HtmlExporter, ics::IcsExporter,
+    ndjson::NdjsonExporter, org::OrgExporter, summary::SummaryExporter,
+    timeclock::TimeclockExporter, timesheet::TimesheetExporter, timewarrior::TimewarriorExporter,
+    worklog::WorklogExporter, xlsx::XlsxExporter,
+};
+
+const ROW_DATE_TIME_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%:z";
+
+pub trait Exporter {
+    /// Name used in the config and in the End tab, e.g. `xlsx`
+    fn name(&self) -> &'static str;
+
+    /// Writes the report into `output_dir` and returns the path of the created file
+    fn export(&self, report: &Report, output_dir: &Path) -> Result<String, String>;
+}
+
+/// Flat representation of a completed task shared by the tabular formats
+#[derive(Serialize)]
+pub struct TaskRow {
+    pub id: String,
+    pub name: String,
+    pub comment: Option<String>,
+    pub tag: Option<String>,
+    pub project: Option<String>,
+    pub start: String,
+    pub end: String,
+    pub duration_seconds: i64,
+}
+
+impl TaskRow {
+    pub fn from_task(task: &CompletedTask) -> TaskRow {
+        TaskRow {
+            id: task.id(),
+            name: task.name_without_comment(),
+            comment: task.end_comment.to_owned(),
+            tag: task.tag.to_owned(),
+            project: task.project.to_owned(),
+            start: task.dt_start.format(ROW_DATE_TIME_FORMAT).to_string(),
+            end: task.dt_end.format(ROW_DATE_TIME_FORMAT).to_string(),
+            duration_seconds: task.duration().num_seconds(),
+        }
+    }
+}
+
+pub fn registry(config: &Config) -> Vec<Box<dyn Exporter>> {
+    vec![
+        Box::new(XlsxExporter {
+            layout: config.xlsx.clone(),
+        }),
+        Box::new(TimesheetExporter {
+            layout: config.xlsx.clone(),
+        }),
+        Box::new(WorklogExporter {
+            format: config.worklog.format,
+        }),
+        Box::new(CsvExporter {
+            delimiter: config.csv.delimiter,
+        }),
+        Box::new(NdjsonExporter),
+        Box::new(IcsExporter),
+        Box::new(HtmlExporter),
+        Box::new(EmlExporter {
+            email: config.email.clone(),
+            layout: config.xlsx.clone(),
+        }),
+        Box::new(OrgExporter),
+        Box::new(TimeclockExporter {
+            config: config.timeclock.clone(),
+        }),
+        Box::new(TimewarriorExporter {
+            data_dir: config.timewarrior.data_dir.to_owned(),
+        }),
+        Box::new(SummaryExporter {
+            format: SummaryFormat::Markdown,
+        }),
+        Box::new(SummaryExporter {
+            format: SummaryFormat::PlainText,
+        }),
+    ]
+}
+
+pub fn export_report(
+    exporters: &[Box<dyn Exporter>],
+    formats: &[String],
+    report: &Report,
+    output_dir: &Path,
+) -> Vec<Result<String, String>> {
+    formats
+        .iter()
+        .map(|format| {
+            match exporters
+                .iter()
+                .find(|exporter| exporter.name() == format.as_str())
+            {
+                Some(exporter) => exporter.export(report, output_dir),
+                None => Err(format!("Unknown export format: {}", format)),
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,41 @@
+use std::fs::File;
+use std::io::prelude::*;
+use std::path::Path;
+
+use crate::report::Report;
+
+use super::{Exporter, TaskRow};
+
+pub struct NdjsonExporter;
+
+impl Exporter for NdjsonExporter {
+    fn name(&self) -> &'static str {
+        "ndjson"
+    }
+
+    fn export(&self, report: &Report, output_dir: &Path) -> Result<String, String> {
+        let mut content = String::new();
+
+        for task in report.tasks() {
+            let row = TaskRow::from_task(task);
+
+            match serde_json::to_string(&row) {
+                Ok(line) => {
+                    content.push_str(&line);
+                    content.push('\n');
+                }
+                Err(_) => return Err("Could not serialize task as JSON".to_owned()),
+            }
+        }
+
+        let file_path = output_dir.join(format!("{}.ndjson", report.file_stem()));
+
+        let write_result =
+            File::create(&file_path).and_then(|mut file| file.write_all(content.as_bytes()));
+
+        match write_result {
+            Ok(_) => Ok(file_path.to_string_lossy().to_string()),
+            Err(_) => Err("Could not write NDJSON file".to_owned()),
+        }
+    }
+}
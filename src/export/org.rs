@@ -0,0 +1,114 @@
+use std::fs::File;
+use std::io::prelude::*;
+use std::path::Path;
+
+use crate::{report::Report, task::CompletedTask};
+
+use super::Exporter;
+
+pub const ORG_TIMESTAMP_FORMAT: &str = "%Y-%m-%d %a %H:%M";
+
+pub const ORG_TAG_PROPERTY: &str = "TAG";
+pub const ORG_PROJECT_PROPERTY: &str = "PROJECT";
+pub const ORG_COMMENT_PROPERTY: &str = "COMMENT";
+
+/// Org file with a heading per day and a heading with a `:LOGBOOK:` clock line per task
+pub struct OrgExporter;
+
+impl Exporter for OrgExporter {
+    fn name(&self) -> &'static str {
+        "org"
+    }
+
+    fn export(&self, report: &Report, output_dir: &Path) -> Result<String, String> {
+        let mut lines = vec![];
+
+        for day in &report.days {
+            lines.push(format!("* {}", day.date.format("[%Y-%m-%d %a]")));
+
+            for task in &day.tasks {
+                lines.extend(task_as_heading(task));
+            }
+        }
+
+        let content: String = lines.iter().map(|line| format!("{}\n", line)).collect();
+
+        let file_path = output_dir.join(format!("{}.org", report.file_stem()));
+
+        let write_result =
+            File::create(&file_path).and_then(|mut file| file.write_all(content.as_bytes()));
+
+        match write_result {
+            Ok(_) => Ok(file_path.to_string_lossy().to_string()),
+            Err(_) => Err("Could not write org file".to_owned()),
+        }
+    }
+}
+
+/// Org tags only allow letters, digits, `_`, `@`, `#` and `%`, so the exact
+/// tag and project are kept in the properties drawer as well
+fn task_as_heading(task: &CompletedTask) -> Vec<String> {
+    let org_tags: Vec<String> = task
+        .tag
+        .iter()
+        .chain(task.project.iter())
+        .map(|tag| as_org_tag(tag))
+        .collect();
+
+    let heading = if org_tags.is_empty() {
+        format!("** {}", task.name_without_comment())
+    } else {
+        format!(
+            "** {} :{}:",
+            task.name_without_comment(),
+            org_tags.join(":")
+        )
+    };
+
+    let mut lines = vec![heading];
+
+    let properties: Vec<(&str, &String)> = [
+        (ORG_TAG_PROPERTY, &task.tag),
+        (ORG_PROJECT_PROPERTY, &task.project),
+        (ORG_COMMENT_PROPERTY, &task.end_comment),
+    ]
+    .into_iter()
+    .filter_map(|(name, value)| value.as_ref().map(|value| (name, value)))
+    .collect();
+
+    if !properties.is_empty() {
+        lines.push(":PROPERTIES:".to_owned());
+        lines.extend(
+            properties
+                .into_iter()
+                .map(|(name, value)| format!(":{}: {}", name, value.replace('\n', " "))),
+        );
+        lines.push(":END:".to_owned());
+    }
+
+    let minutes = task.duration().num_minutes();
+
+    lines.push(":LOGBOOK:".to_owned());
+    lines.push(format!(
+        "CLOCK: [{}]--[{}] => {:>2}:{:02}",
+        task.dt_start.format(ORG_TIMESTAMP_FORMAT),
+        task.dt_end.format(ORG_TIMESTAMP_FORMAT),
+        minutes / 60,
+        minutes % 60
+    ));
+    lines.push(":END:".to_owned());
+
+    lines
+}
+
+pub fn as_org_tag(tag: &str) -> String {
+    tag.chars()
+        .map(|char| {
+            if char.is_alphanumeric() || "_@#%".contains(char) {
+                char
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,53 @@
+use std::fs::File;
+use std::io::prelude::*;
+use std::path::Path;
+
+use crate::{
+    report::Report,
+    summary::{Summary, SummaryFormat},
+};
+
+use super::Exporter;
+
+/// Standup summary, one section per day of the report
+pub struct SummaryExporter {
+    pub format: SummaryFormat,
+}
+
+impl SummaryExporter {
+    pub fn extension(&self) -> &'static str {
+        match self.format {
+            SummaryFormat::Markdown => "md",
+            SummaryFormat::PlainText => "txt",
+        }
+    }
+}
+
+impl Exporter for SummaryExporter {
+    fn name(&self) -> &'static str {
+        self.extension()
+    }
+
+    fn export(&self, report: &Report, output_dir: &Path) -> Result<String, String> {
+        let content = report
+            .days
+            .iter()
+            .map(|day| Summary::from_day(day).render(self.format))
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        let file_path = output_dir.join(format!(
+            "{}.summary.{}",
+            report.file_stem(),
+            self.extension()
+        ));
+
+        let write_result =
+            File::create(&file_path).and_then(|mut file| file.write_all(content.as_bytes()));
+
+        match write_result {
+            Ok(_) => Ok(file_path.to_string_lossy().to_string()),
+            Err(_) => Err("Could not write summary file".to_owned()),
+        }
+    }
+}
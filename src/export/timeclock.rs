@@ -0,0 +1,75 @@
+use std::fs::File;
+use std::io::prelude::*;
+use std::path::Path;
+
+use crate::{
+    config::{TimeclockAccountSource, TimeclockConfig},
+    report::Report,
+    task::CompletedTask,
+};
+
+use super::Exporter;
+
+const TIMECLOCK_DATE_TIME_FORMAT: &str = "%Y/%m/%d %H:%M:%S";
+const TIMECLOCK_FALLBACK_ACCOUNT: &str = "other";
+
+/// Clock-in and clock-out pairs in the timeclock format read by hledger
+pub struct TimeclockExporter {
+    pub config: TimeclockConfig,
+}
+
+impl Exporter for TimeclockExporter {
+    fn name(&self) -> &'static str {
+        "timeclock"
+    }
+
+    fn export(&self, report: &Report, output_dir: &Path) -> Result<String, String> {
+        let content: String = report
+            .tasks()
+            .map(|task| {
+                format!(
+                    "i {} {}  {}\no {}\n",
+                    task.dt_start.format(TIMECLOCK_DATE_TIME_FORMAT),
+                    self.account(task),
+                    task.name.replace(['\n', '\r'], " "),
+                    task.dt_end.format(TIMECLOCK_DATE_TIME_FORMAT)
+                )
+            })
+            .collect();
+
+        let file_path = output_dir.join(format!("{}.timeclock", report.file_stem()));
+
+        let write_result =
+            File::create(&file_path).and_then(|mut file| file.write_all(content.as_bytes()));
+
+        match write_result {
+            Ok(_) => Ok(file_path.to_string_lossy().to_string()),
+            Err(_) => Err("Could not write timeclock file".to_owned()),
+        }
+    }
+}
+
+impl TimeclockExporter {
+    /// `work:backend`, the configured source is used first and the other one as a fallback
+    fn account(&self, task: &CompletedTask) -> String {
+        let (primary, secondary) = match self.config.account_from {
+            TimeclockAccountSource::Project => (&task.project, &task.tag),
+            TimeclockAccountSource::Tag => (&task.tag, &task.project),
+        };
+
+        let name = primary
+            .as_ref()
+            .or(secondary.as_ref())
+            .map(|name| name.as_str())
+            .unwrap_or(TIMECLOCK_FALLBACK_ACCOUNT);
+
+        // Two spaces end the account name in timeclock lines
+        let name = name.split_whitespace().collect::<Vec<&str>>().join("-");
+
+        if self.config.account_prefix.is_empty() {
+            name
+        } else {
+            format!("{}:{}", self.config.account_prefix, name)
+        }
+    }
+}
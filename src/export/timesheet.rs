@@ -0,0 +1,190 @@
+use std::path::Path;
+
+use rust_xlsxwriter::{Format, Formula, Workbook, Worksheet};
+
+use crate::{
+    config::{XlsxConfig, XlsxField},
+    report::{Report, ReportDay, NO_PROJECT, NO_TAG},
+};
+
+use super::{
+    xlsx::{base_format, task_rows_range, write_day_sheet},
+    Exporter,
+};
+
+const SUMMARY_SHEET_NAME: &str = "Summary";
+
+/// Workbook for a week or a month: a summary sheet and one sheet per archived day
+pub struct TimesheetExporter {
+    pub layout: XlsxConfig,
+}
+
+impl Exporter for TimesheetExporter {
+    fn name(&self) -> &'static str {
+        "timesheet"
+    }
+
+    fn export(&self, report: &Report, output_dir: &Path) -> Result<String, String> {
+        let mut workbook = Workbook::new();
+
+        let summary_sheet = workbook.add_worksheet();
+        write_summary_sheet(summary_sheet, report, &self.layout);
+
+        for day in &report.days {
+            let worksheet = workbook.add_worksheet();
+
+            write_day_sheet(worksheet, day, &self.layout);
+        }
+
+        let file_path = output_dir.join(format!("{}.timesheet.xlsx", report.file_stem()));
+
+        let save_result = workbook.save(&file_path);
+
+        match save_result {
+            Ok(_) => Ok(file_path.to_string_lossy().to_string()),
+            Err(_) => Err("Could not save timesheet workbook".to_owned()),
+        }
+    }
+}
+
+fn write_summary_sheet(worksheet: &mut Worksheet, report: &Report, layout: &XlsxConfig) {
+    let cell_format = base_format(layout);
+    let header_format = cell_format.clone().set_bold();
+    let hours_format = cell_format.clone().set_num_format("0.00");
+    let total_format = hours_format.clone().set_bold();
+
+    let _ = worksheet.set_name(SUMMARY_SHEET_NAME);
+    let _ = worksheet.set_column_width(0, 25);
+    let _ = worksheet.set_column_width(1, 12);
+
+    let _ = worksheet.write_with_format(
+        0,
+        0,
+        format!("Timesheet {}", report.file_stem()),
+        &header_format,
+    );
+
+    let _ = worksheet.write_with_format(2, 0, "Date", &header_format);
+    let _ = worksheet.write_with_format(2, 1, "Hours", &header_format);
+
+    let first_day_row_idx: u32 = 3;
+
+    for (idx, day) in report.days.iter().enumerate() {
+        let row_idx = first_day_row_idx + u32::try_from(idx).unwrap();
+
+        let formula = match sheet_range(day, layout, XlsxField::Hours) {
+            Some(hours_range) => format!("=SUM({})", hours_range),
+            None => "=0".to_owned(),
+        };
+
+        let _ = worksheet.write_with_format(row_idx, 0, sheet_name(day), &cell_format);
+        let _ =
+            worksheet.write_formula_with_format(row_idx, 1, Formula::new(formula), &hours_format);
+    }
+
+    let total_row_idx = first_day_row_idx + u32::try_from(report.days.len()).unwrap();
+
+    let _ = worksheet.write_with_format(total_row_idx, 0, "Total", &header_format);
+    let _ = worksheet.write_formula_with_format(
+        total_row_idx,
+        1,
+        Formula::new(format!(
+            "=SUM(B{}:B{})",
+            first_day_row_idx + 1,
+            total_row_idx.max(first_day_row_idx + 1)
+        )),
+        &total_format,
+    );
+
+    let tags: Vec<String> = report
+        .hours_by_tag()
+        .into_iter()
+        .map(|(tag, _)| tag)
+        .collect();
+
+    let tags_row_idx = total_row_idx + 2;
+
+    write_grouped_hours(
+        worksheet,
+        (report, layout),
+        tags_row_idx,
+        ("Tag", XlsxField::Tag, NO_TAG),
+        &tags,
+        (&header_format, &cell_format, &hours_format),
+    );
+
+    let projects: Vec<String> = report
+        .hours_by_project()
+        .into_iter()
+        .map(|(project, _)| project)
+        .collect();
+
+    let projects_row_idx = tags_row_idx + u32::try_from(tags.len()).unwrap() + 2;
+
+    write_grouped_hours(
+        worksheet,
+        (report, layout),
+        projects_row_idx,
+        ("Project", XlsxField::Project, NO_PROJECT),
+        &projects,
+        (&header_format, &cell_format, &hours_format),
+    );
+}
+
+/// Hours per group summed with SUMIF over every day sheet
+fn write_grouped_hours(
+    worksheet: &mut Worksheet,
+    (report, layout): (&Report, &XlsxConfig),
+    start_row_idx: u32,
+    (header, field, empty_label): (&str, XlsxField, &str),
+    groups: &[String],
+    (header_format, cell_format, hours_format): (&Format, &Format, &Format),
+) {
+    let _ = worksheet.write_with_format(start_row_idx, 0, header, header_format);
+    let _ = worksheet.write_with_format(start_row_idx, 1, "Hours", header_format);
+
+    for (idx, group) in groups.iter().enumerate() {
+        let row_idx = start_row_idx + 1 + u32::try_from(idx).unwrap();
+
+        let criteria = if group == empty_label {
+            String::new()
+        } else {
+            group.replace('"', "\"\"")
+        };
+
+        let sumifs: Vec<String> = report
+            .days
+            .iter()
+            .filter_map(|day| {
+                let criteria_range = sheet_range(day, layout, field)?;
+                let hours_range = sheet_range(day, layout, XlsxField::Hours)?;
+
+                Some(format!(
+                    "SUMIF({},\"{}\",{})",
+                    criteria_range, criteria, hours_range
+                ))
+            })
+            .collect();
+
+        let formula = if sumifs.is_empty() {
+            "=0".to_owned()
+        } else {
+            format!("={}", sumifs.join("+"))
+        };
+
+        let _ = worksheet.write_with_format(row_idx, 0, group, cell_format);
+        let _ =
+            worksheet.write_formula_with_format(row_idx, 1, Formula::new(formula), hours_format);
+    }
+}
+
+fn sheet_name(day: &ReportDay) -> String {
+    day.date.format("%d.%m.%Y").to_string()
+}
+
+/// Range of the task rows on a day sheet, e.g. `'15.03.2024'!$G$3:$G$8`
+fn sheet_range(day: &ReportDay, layout: &XlsxConfig, field: XlsxField) -> Option<String> {
+    let range = task_rows_range(day, layout, field)?;
+
+    Some(format!("'{}'!{}", sheet_name(day), range))
+}
@@ -0,0 +1,149 @@
+use std::collections::BTreeSet;
+use std::fs::{create_dir_all, File};
+use std::io::prelude::*;
+use std::path::{Path, PathBuf};
+
+use chrono::prelude::*;
+use itertools::Itertools;
+
+use crate::{report::Report, task::CompletedTask};
+
+use super::Exporter;
+
+pub const TIMEWARRIOR_DATE_TIME_FORMAT: &str = "%Y%m%dT%H%M%SZ";
+pub const TIMEWARRIOR_PROJECT_TAG_PREFIX: &str = "project:";
+
+/// Timewarrior `inc` lines merged into the monthly `yyyy-mm.data` files
+pub struct TimewarriorExporter {
+    pub data_dir: Option<PathBuf>,
+}
+
+impl Exporter for TimewarriorExporter {
+    fn name(&self) -> &'static str {
+        "timewarrior"
+    }
+
+    fn export(&self, report: &Report, output_dir: &Path) -> Result<String, String> {
+        // The running task would be merged with a temporary end and again on End
+        if report.is_snapshot {
+            return export_snapshot(report, output_dir);
+        }
+
+        let data_dir = self.data_dir.to_owned().unwrap_or(output_dir.to_path_buf());
+
+        if create_dir_all(&data_dir).is_err() {
+            return Err("Could not create Timewarrior data directory".to_owned());
+        }
+
+        let tasks_by_month = report.tasks().into_group_map_by(|task| {
+            task.dt_start
+                .with_timezone(&Utc)
+                .format("%Y-%m")
+                .to_string()
+        });
+
+        let mut file_paths = vec![];
+
+        for (month, tasks) in tasks_by_month.into_iter().sorted_by(|a, b| a.0.cmp(&b.0)) {
+            let file_path = data_dir.join(format!("{}.data", month));
+
+            let mut lines: BTreeSet<String> = BTreeSet::new();
+
+            if file_path.exists() {
+                let mut string = String::new();
+
+                let read_result =
+                    File::open(&file_path).and_then(|mut file| file.read_to_string(&mut string));
+
+                if read_result.is_err() {
+                    return Err(format!("Could not read {}", file_path.to_string_lossy()));
+                }
+
+                lines.extend(
+                    string
+                        .lines()
+                        .filter(|line| !line.trim().is_empty())
+                        .map(|line| line.to_owned()),
+                );
+            }
+
+            lines.extend(tasks.into_iter().map(task_as_inc_line));
+
+            let content: String = lines.iter().map(|line| format!("{}\n", line)).collect();
+
+            let write_result =
+                File::create(&file_path).and_then(|mut file| file.write_all(content.as_bytes()));
+
+            match write_result {
+                Ok(_) => file_paths.push(file_path.to_string_lossy().to_string()),
+                Err(_) => return Err("Could not write Timewarrior data file".to_owned()),
+            }
+        }
+
+        Ok(file_paths.join(", "))
+    }
+}
+
+/// A snapshot goes to a separate file next to the other reports and is never merged into `data_dir`
+fn export_snapshot(report: &Report, output_dir: &Path) -> Result<String, String> {
+    let file_path = output_dir.join(format!("{}.data", report.file_stem()));
+
+    let content: String = report
+        .tasks()
+        .map(|task| format!("{}\n", task_as_inc_line(task)))
+        .collect();
+
+    let write_result =
+        File::create(&file_path).and_then(|mut file| file.write_all(content.as_bytes()));
+
+    match write_result {
+        Ok(_) => Ok(file_path.to_string_lossy().to_string()),
+        Err(_) => Err("Could not write Timewarrior data file".to_owned()),
+    }
+}
+
+/// `inc 20261018T090000Z - 20261018T100000Z # "task name" tag project:name # "comment"`,
+/// the task name is the first tag and the end comment the annotation
+fn task_as_inc_line(task: &CompletedTask) -> String {
+    let tags: Vec<String> = [task.name_without_comment()]
+        .into_iter()
+        .chain(task.tag.iter().cloned())
+        .chain(
+            task.project
+                .iter()
+                .map(|project| format!("{}{}", TIMEWARRIOR_PROJECT_TAG_PREFIX, project)),
+        )
+        .map(|tag| quote_if_needed(&tag))
+        .collect();
+
+    let tags: String = tags.iter().map(|tag| format!(" {}", tag)).collect();
+
+    let annotation = match &task.end_comment {
+        Some(comment) => format!(" # {}", quote(comment)),
+        None => String::new(),
+    };
+
+    format!(
+        "inc {} - {} #{}{}",
+        task.dt_start
+            .with_timezone(&Utc)
+            .format(TIMEWARRIOR_DATE_TIME_FORMAT),
+        task.dt_end
+            .with_timezone(&Utc)
+            .format(TIMEWARRIOR_DATE_TIME_FORMAT),
+        tags,
+        annotation
+    )
+}
+
+fn quote(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn quote_if_needed(text: &str) -> String {
+    if text.contains(|char: char| char.is_whitespace() || char == '"' || char == '#') {
+        quote(text)
+    } else {
+        text.to_owned()
+    }
+}
@@ -0,0 +1,104 @@
+use std::fs::File;
+use std::io::prelude::*;
+use std::path::Path;
+
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+
+use crate::{config::WorklogFormat, report::Report, task::CompletedTask, utils::escape_csv_field};
+
+use super::Exporter;
+
+const JIRA_DATE_TIME_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.3f%z";
+
+pub struct WorklogExporter {
+    pub format: WorklogFormat,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorklogEntry {
+    pub issue_key: String,
+    pub started: String,
+    pub time_spent_seconds: i64,
+    pub comment: String,
+}
+
+/// Groups tasks with an issue key into one entry per issue and day
+pub fn collect_worklog_entries(completed_tasks: &[CompletedTask]) -> Vec<WorklogEntry> {
+    completed_tasks
+        .iter()
+        .filter(|task| task.issue.is_some())
+        .into_group_map_by(|task| (task.issue.to_owned().unwrap(), task.dt_start.date_naive()))
+        .into_iter()
+        .map(|((issue_key, _date), tasks)| {
+            let started = tasks.iter().map(|task| task.dt_start).min().unwrap();
+
+            let time_spent_seconds = tasks
+                .iter()
+                .map(|task| (task.dt_end - task.dt_start).num_seconds())
+                .sum();
+
+            let comment = tasks
+                .iter()
+                .map(|task| task.name.to_owned())
+                .unique()
+                .join("; ");
+
+            WorklogEntry {
+                issue_key,
+                started: started.format(JIRA_DATE_TIME_FORMAT).to_string(),
+                time_spent_seconds,
+                comment,
+            }
+        })
+        .sorted_by(|a, b| a.started.cmp(&b.started))
+        .collect()
+}
+
+impl Exporter for WorklogExporter {
+    fn name(&self) -> &'static str {
+        "worklog"
+    }
+
+    fn export(&self, report: &Report, output_dir: &Path) -> Result<String, String> {
+        let completed_tasks: Vec<CompletedTask> = report.tasks().cloned().collect();
+        let entries = collect_worklog_entries(&completed_tasks);
+
+        let (content, extension) = match self.format {
+            WorklogFormat::Json => match serde_json::to_string_pretty(&entries) {
+                Ok(json) => (json, "json"),
+                Err(_) => return Err("Could not serialize worklog as JSON".to_owned()),
+            },
+            WorklogFormat::Csv => (worklog_as_csv(&entries), "csv"),
+        };
+
+        let file_path = output_dir.join(format!("{}.worklog.{}", report.file_stem(), extension));
+
+        let write_result =
+            File::create(&file_path).and_then(|mut file| file.write_all(content.as_bytes()));
+
+        match write_result {
+            Ok(_) => Ok(file_path.to_string_lossy().to_string()),
+            Err(_) => Err("Could not write worklog file".to_owned()),
+        }
+    }
+}
+
+fn worklog_as_csv(entries: &[WorklogEntry]) -> String {
+    let mut lines = vec!["issueKey,started,timeSpentSeconds,comment".to_owned()];
+
+    for entry in entries {
+        lines.push(
+            [
+                escape_csv_field(&entry.issue_key, ','),
+                escape_csv_field(&entry.started, ','),
+                entry.time_spent_seconds.to_string(),
+                escape_csv_field(&entry.comment, ','),
+            ]
+            .join(","),
+        );
+    }
+
+    lines.join("\r\n") + "\r\n"
+}
@@ -0,0 +1,450 @@
+use std::path::Path;
+
+use chrono::prelude::*;
+use itertools::Itertools;
+use rust_xlsxwriter::{
+    column_number_to_name, Chart, ChartType, ExcelDateTime, Format, Formula, Workbook, Worksheet,
+};
+
+use crate::{
+    config::{XlsxConfig, XlsxField},
+    report::{task_hours, Report, ReportDay, NO_TAG},
+    task::CompletedTask,
+};
+
+use super::Exporter;
+
+const SECONDS_IN_DAY: f64 = 86400_f64;
+
+/// Rows taken by the tags chart in the side area, with the default chart height of 288px
+const CHART_ROWS: u32 = 16;
+
+pub struct XlsxExporter {
+    pub layout: XlsxConfig,
+}
+
+impl Exporter for XlsxExporter {
+    fn name(&self) -> &'static str {
+        "xlsx"
+    }
+
+    fn export(&self, report: &Report, output_dir: &Path) -> Result<String, String> {
+        let mut workbook = self.workbook(report);
+
+        let file_path = output_dir.join(format!("{}.xlsx", report.file_stem()));
+
+        let save_result = workbook.save(&file_path);
+
+        match save_result {
+            Ok(_) => Ok(file_path.to_string_lossy().to_string()),
+            Err(_) => Err("Could not save workbook".to_owned()),
+        }
+    }
+}
+
+impl XlsxExporter {
+    /// One sheet per day of the report
+    pub fn workbook(&self, report: &Report) -> Workbook {
+        let mut workbook = Workbook::new();
+
+        for day in &report.days {
+            let worksheet = workbook.add_worksheet();
+
+            write_day_sheet(worksheet, day, &self.layout);
+        }
+
+        workbook
+    }
+}
+
+/// Range of the task rows of a day sheet in the given column, e.g. `G3:G8`
+pub(super) fn task_rows_range(
+    day: &ReportDay,
+    layout: &XlsxConfig,
+    field: XlsxField,
+) -> Option<String> {
+    if day.tasks.is_empty() {
+        return None;
+    }
+
+    let column = column_number_to_name(layout.column_idx(field)?);
+    let first_row = layout.first_task_row() + 1;
+    let last_row = layout.first_task_row() + u32::try_from(day.tasks.len()).unwrap();
+
+    Some(format!("${0}${1}:${0}${2}", column, first_row, last_row))
+}
+
+pub(super) fn base_format(layout: &XlsxConfig) -> Format {
+    Format::new()
+        .set_text_wrap()
+        .set_background_color(layout.background_color.as_str())
+        .set_font_name(&layout.font_name)
+        .set_font_size(layout.font_size)
+}
+
+pub(super) fn write_day_sheet(worksheet: &mut Worksheet, day: &ReportDay, layout: &XlsxConfig) {
+    let title_format = base_format(layout).set_bold();
+
+    let date = day.date.format("%d.%m.%Y").to_string();
+
+    let _ = worksheet.set_name(&date);
+
+    let _ = worksheet.write_with_format(0, 0, &date, &title_format);
+
+    let column_formats: Vec<Format> = layout
+        .columns
+        .iter()
+        .map(|column| {
+            let mut format = base_format(layout);
+
+            if let Some(num_format) = &column.num_format {
+                format = format.set_num_format(num_format);
+            }
+
+            if column.bold {
+                format = format.set_bold();
+            }
+
+            format
+        })
+        .collect();
+
+    for (col_idx, column) in layout.columns.iter().enumerate() {
+        let col_idx: u16 = col_idx.try_into().unwrap();
+
+        if let Some(width) = column.width {
+            let _ = worksheet.set_column_width(col_idx, width);
+        }
+
+        if layout.header_row {
+            let _ = worksheet.write_with_format(1, col_idx, &column.header, &title_format);
+        }
+    }
+
+    let first_task_row = layout.first_task_row();
+
+    for (idx, task) in day.tasks.iter().enumerate() {
+        let row_idx = first_task_row + u32::try_from(idx).unwrap();
+
+        let _ = worksheet.set_row_height(row_idx, layout.row_height);
+
+        for (col_idx, column) in layout.columns.iter().enumerate() {
+            write_task_cell(
+                worksheet,
+                (row_idx, col_idx.try_into().unwrap()),
+                column.field,
+                task,
+                &date,
+                layout,
+                &column_formats[col_idx],
+            );
+        }
+    }
+
+    let last_col_idx: u16 = layout.columns.len().saturating_sub(1).try_into().unwrap();
+    let total_row_idx = first_task_row + u32::try_from(day.tasks.len()).unwrap();
+
+    if layout.freeze_panes {
+        let _ = worksheet.set_freeze_panes(first_task_row, 0);
+    }
+
+    if layout.autofilter && layout.header_row && !layout.columns.is_empty() {
+        let _ = worksheet.autofilter(1, 0, total_row_idx - 1, last_col_idx);
+    }
+
+    if day.tasks.is_empty() {
+        return;
+    }
+
+    write_total_row(worksheet, day, layout, total_row_idx);
+
+    let tags_row_idx = total_row_idx + 2;
+    let tags_count = write_tags_section(worksheet, day, layout, &date, tags_row_idx);
+
+    // The plan section goes to the side area right of the configured columns, under the chart
+    if !day.plan.items.is_empty() {
+        let plan_row_idx = if tags_count > 0 { CHART_ROWS } else { 0 };
+
+        write_plan_section(worksheet, day, layout, plan_row_idx);
+    }
+}
+
+fn write_task_cell(
+    worksheet: &mut Worksheet,
+    (row_idx, col_idx): (u32, u16),
+    field: XlsxField,
+    task: &CompletedTask,
+    date: &str,
+    layout: &XlsxConfig,
+    format: &Format,
+) {
+    let excel_row = row_idx + 1;
+    let cell_of = |field| {
+        layout
+            .column_idx(field)
+            .map(|col_idx| format!("{}{}", column_number_to_name(col_idx), excel_row))
+    };
+
+    let text = match field {
+        XlsxField::Date => Some(date.to_owned()),
+        XlsxField::Tag => Some(task.tag.to_owned().unwrap_or_default()),
+        XlsxField::Name => Some(task.name.to_owned()),
+        XlsxField::Comment => Some(task.end_comment.to_owned().unwrap_or_default()),
+        XlsxField::Project => Some(task.project.to_owned().unwrap_or_default()),
+        XlsxField::Issue => Some(task.issue.to_owned().unwrap_or_default()),
+        _ => None,
+    };
+
+    if let Some(text) = text {
+        let _ = worksheet.write_with_format(row_idx, col_idx, text, format);
+
+        return;
+    }
+
+    match field {
+        XlsxField::Start => {
+            let _ =
+                worksheet.write_with_format(row_idx, col_idx, excel_time(&task.dt_start), format);
+        }
+        XlsxField::End => {
+            let _ = worksheet.write_with_format(row_idx, col_idx, excel_time(&task.dt_end), format);
+        }
+        XlsxField::Duration => match (cell_of(XlsxField::Start), cell_of(XlsxField::End)) {
+            (Some(start_cell), Some(end_cell)) => {
+                let time_difference_formula = Formula::new(format!("={}-{}", end_cell, start_cell));
+
+                let _ = worksheet.write_formula_with_format(
+                    row_idx,
+                    col_idx,
+                    time_difference_formula,
+                    format,
+                );
+            }
+            _ => {
+                let duration = task.duration().num_seconds() as f64 / SECONDS_IN_DAY;
+
+                let _ = worksheet.write_with_format(row_idx, col_idx, duration, format);
+            }
+        },
+        XlsxField::Hours => match cell_of(XlsxField::Duration) {
+            Some(duration_cell) => {
+                let hours_total_formula = Formula::new(format!(
+                    "=ROUND(HOUR({0})+MINUTE({0})/60+SECOND({0})/3600, 2)",
+                    duration_cell
+                ));
+
+                let _ = worksheet.write_formula_with_format(
+                    row_idx,
+                    col_idx,
+                    hours_total_formula,
+                    format,
+                );
+            }
+            None => {
+                let hours = (task_hours(task) * 100_f64).round() / 100_f64;
+
+                let _ = worksheet.write_with_format(row_idx, col_idx, hours, format);
+            }
+        },
+        _ => {}
+    }
+}
+
+fn excel_time(dt: &DateTime<Local>) -> ExcelDateTime {
+    ExcelDateTime::from_hms(
+        dt.hour().try_into().unwrap(),
+        dt.minute().try_into().unwrap(),
+        dt.second(),
+    )
+    .unwrap()
+}
+
+fn write_total_row(worksheet: &mut Worksheet, day: &ReportDay, layout: &XlsxConfig, row_idx: u32) {
+    let (Some(hours_col_idx), Some(hours_range)) = (
+        layout.column_idx(XlsxField::Hours),
+        task_rows_range(day, layout, XlsxField::Hours),
+    ) else {
+        return;
+    };
+
+    let total_format = base_format(layout).set_num_format("0.00").set_bold();
+
+    let label_col_idx = layout
+        .column_idx(XlsxField::Name)
+        .filter(|col_idx| *col_idx != hours_col_idx)
+        .unwrap_or(0);
+
+    let total_formula = Formula::new(format!("=SUM({})", hours_range));
+
+    let _ = worksheet.write_with_format(row_idx, label_col_idx, "Total", &total_format);
+    let _ =
+        worksheet.write_formula_with_format(row_idx, hours_col_idx, total_formula, &total_format);
+}
+
+/// Hours per tag summed with SUMIF over the task rows, with a pie chart next to the tasks.
+/// Returns the number of rows written
+fn write_tags_section(
+    worksheet: &mut Worksheet,
+    day: &ReportDay,
+    layout: &XlsxConfig,
+    sheet_name: &str,
+    section_row_idx: u32,
+) -> u32 {
+    let (Some(tag_col_idx), Some(hours_col_idx)) = (
+        layout.column_idx(XlsxField::Tag),
+        layout.column_idx(XlsxField::Hours),
+    ) else {
+        return 0;
+    };
+
+    let tags_range = task_rows_range(day, layout, XlsxField::Tag).unwrap();
+    let hours_range = task_rows_range(day, layout, XlsxField::Hours).unwrap();
+    let tag_column = column_number_to_name(tag_col_idx);
+
+    let tag_format = base_format(layout);
+    let tag_header_format = tag_format.clone().set_bold();
+    let hours_format = tag_format.clone().set_num_format("0.00");
+
+    let tags: Vec<String> = day
+        .tasks
+        .iter()
+        .map(|task| task.tag.to_owned().unwrap_or_default())
+        .unique()
+        .sorted()
+        .collect();
+
+    let _ = worksheet.write_with_format(section_row_idx, tag_col_idx, "Tag", &tag_header_format);
+    let _ =
+        worksheet.write_with_format(section_row_idx, hours_col_idx, "Hours", &tag_header_format);
+
+    for (idx, tag) in tags.iter().enumerate() {
+        let row_idx = section_row_idx + 1 + u32::try_from(idx).unwrap();
+
+        let tag_hours_formula = if tag.is_empty() {
+            Formula::new(format!("=SUMIF({},\"\",{})", tags_range, hours_range))
+        } else {
+            Formula::new(format!(
+                "=SUMIF({},{}{},{})",
+                tags_range,
+                tag_column,
+                row_idx + 1,
+                hours_range
+            ))
+        };
+
+        let tag_label = if tag.is_empty() { NO_TAG } else { tag };
+
+        let _ = worksheet.write_with_format(row_idx, tag_col_idx, tag_label, &tag_format);
+        let _ = worksheet.write_formula_with_format(
+            row_idx,
+            hours_col_idx,
+            tag_hours_formula,
+            &hours_format,
+        );
+    }
+
+    let tags_count: u32 = tags.len().try_into().unwrap();
+    let first_tag_row_idx = section_row_idx + 1;
+    let last_tag_row_idx = section_row_idx + tags_count;
+
+    let mut chart = Chart::new(ChartType::Pie);
+
+    chart
+        .add_series()
+        .set_name("Hours by tag")
+        .set_categories((
+            sheet_name,
+            first_tag_row_idx,
+            tag_col_idx,
+            last_tag_row_idx,
+            tag_col_idx,
+        ))
+        .set_values((
+            sheet_name,
+            first_tag_row_idx,
+            hours_col_idx,
+            last_tag_row_idx,
+            hours_col_idx,
+        ));
+    chart.title().set_name("Hours by tag");
+
+    let _ = worksheet.insert_chart(0, side_col_idx(layout), &chart);
+
+    tags_count
+}
+
+/// First column after the configured ones and an empty spacer column
+fn side_col_idx(layout: &XlsxConfig) -> u16 {
+    (layout.columns.len() + 1).try_into().unwrap()
+}
+
+fn write_plan_section(
+    worksheet: &mut Worksheet,
+    day: &ReportDay,
+    layout: &XlsxConfig,
+    section_row_idx: u32,
+) {
+    let plan_rows = day.plan.compare_with_actual(&day.tasks, None);
+
+    let plan_format = base_format(layout);
+    let plan_header_format = plan_format.clone().set_bold();
+    let hours_format = plan_format.clone().set_num_format("0.00");
+
+    let kind_col_idx = side_col_idx(layout);
+    let name_col_idx = kind_col_idx + 1;
+    let estimate_col_idx = kind_col_idx + 2;
+    let actual_col_idx = kind_col_idx + 3;
+    let difference_col_idx = kind_col_idx + 4;
+
+    let estimate_column = column_number_to_name(estimate_col_idx);
+    let actual_column = column_number_to_name(actual_col_idx);
+
+    let _ = worksheet.write_with_format(
+        section_row_idx,
+        name_col_idx,
+        "End of day: plan versus actual",
+        &plan_header_format,
+    );
+
+    for (col_idx, header) in ["", "Task", "Estimate, h", "Actual, h", "Difference, h"]
+        .iter()
+        .enumerate()
+    {
+        let _ = worksheet.write_with_format(
+            section_row_idx + 1,
+            kind_col_idx + u16::try_from(col_idx).unwrap(),
+            *header,
+            &plan_header_format,
+        );
+    }
+
+    for (idx, plan_row) in plan_rows.iter().enumerate() {
+        let row_idx = section_row_idx + 2 + u32::try_from(idx).unwrap();
+
+        let kind = if plan_row.is_planned {
+            "Planned"
+        } else {
+            "Unplanned"
+        };
+        let estimate_hours = plan_row.estimate_minutes.unwrap_or(0) as f64 / 60_f64;
+        let actual_hours = plan_row.actual_minutes as f64 / 60_f64;
+        let difference_formula = Formula::new(format!(
+            "={}{2}-{}{2}",
+            actual_column,
+            estimate_column,
+            row_idx + 1
+        ));
+
+        let _ = worksheet.write_with_format(row_idx, kind_col_idx, kind, &plan_format);
+        let _ = worksheet.write_with_format(row_idx, name_col_idx, &plan_row.name, &plan_format);
+        let _ =
+            worksheet.write_with_format(row_idx, estimate_col_idx, estimate_hours, &hours_format);
+        let _ = worksheet.write_with_format(row_idx, actual_col_idx, actual_hours, &hours_format);
+        let _ = worksheet.write_formula_with_format(
+            row_idx,
+            difference_col_idx,
+            difference_formula,
+            &hours_format,
+        );
+    }
+}
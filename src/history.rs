@@ -0,0 +1,125 @@
+use std::fs::{create_dir_all, read_dir, File};
+use std::io::prelude::*;
+use std::path::PathBuf;
+
+use chrono::prelude::*;
+use itertools::Itertools;
+
+use crate::report::ReportDay;
+
+const HISTORY_DATE_FORMAT: &str = "%Y-%m-%d";
+
+/// Archived days, one `yyyy-mm-dd.json` file per day
+pub struct History {
+    dir: PathBuf,
+}
+
+impl History {
+    pub fn init(dir: &PathBuf) -> Result<History, String> {
+        match create_dir_all(dir) {
+            Ok(_) => Ok(History {
+                dir: dir.to_owned(),
+            }),
+            Err(_) => Err("Could not create history directory".to_owned()),
+        }
+    }
+
+    /// Merges the day into the archive, tasks that are already there are kept once
+    pub fn archive(&self, day: &ReportDay) -> Result<String, String> {
+        let merged_day = match self.load_day(day.date)? {
+            Some(mut archived_day) => {
+                let tasks = archived_day
+                    .tasks
+                    .into_iter()
+                    .chain(day.tasks.iter().cloned())
+                    .unique_by(|task| task.id())
+                    .sorted_by_key(|task| task.dt_start)
+                    .collect();
+
+                archived_day.tasks = tasks;
+                archived_day.start_time = archived_day.start_time.min(day.start_time);
+                archived_day.end_time = archived_day.end_time.max(day.end_time);
+
+                if archived_day.plan.items.is_empty() {
+                    archived_day.plan = day.plan.clone();
+                }
+
+                archived_day
+            }
+            None => day.clone(),
+        };
+
+        let file_path = self.day_file_path(day.date);
+
+        let json = serde_json::to_string_pretty(&merged_day)
+            .map_err(|_| "Could not serialize archived day".to_owned())?;
+
+        let write_result =
+            File::create(&file_path).and_then(|mut file| file.write_all(json.as_bytes()));
+
+        match write_result {
+            Ok(_) => Ok(file_path.to_string_lossy().to_string()),
+            Err(_) => Err("Could not write archived day".to_owned()),
+        }
+    }
+
+    pub fn load_day(&self, date: NaiveDate) -> Result<Option<ReportDay>, String> {
+        let file_path = self.day_file_path(date);
+
+        if !file_path.exists() {
+            return Ok(None);
+        }
+
+        let mut string = String::new();
+
+        let read_result =
+            File::open(&file_path).and_then(|mut file| file.read_to_string(&mut string));
+
+        if read_result.is_err() {
+            return Err(format!("Could not read {}", file_path.to_string_lossy()));
+        }
+
+        match serde_json::from_str(&string) {
+            Ok(day) => Ok(Some(day)),
+            Err(_) => Err(format!(
+                "{} is not a valid day",
+                file_path.to_string_lossy()
+            )),
+        }
+    }
+
+    pub fn load_range(&self, from: NaiveDate, to: NaiveDate) -> Result<Vec<ReportDay>, String> {
+        let mut days = vec![];
+
+        for date in self.archived_dates()? {
+            if date >= from && date <= to {
+                if let Some(day) = self.load_day(date)? {
+                    days.push(day);
+                }
+            }
+        }
+
+        Ok(days)
+    }
+
+    pub fn archived_dates(&self) -> Result<Vec<NaiveDate>, String> {
+        let entries =
+            read_dir(&self.dir).map_err(|_| "Could not read history directory".to_owned())?;
+
+        Ok(entries
+            .filter_map(|entry| entry.ok())
+            .filter_map(|entry| {
+                let file_name = entry.file_name().to_string_lossy().to_string();
+                let date = file_name.strip_suffix(".json")?;
+
+                NaiveDate::parse_from_str(date, HISTORY_DATE_FORMAT).ok()
+            })
+            .sorted()
+            .collect())
+    }
+
+    fn day_file_path(&self, date: NaiveDate) -> PathBuf {
+        self.dir
+            .join(format!("{}.json", date.format(HISTORY_DATE_FORMAT)))
+    }
+}
@@ -0,0 +1,43 @@
+use crate::task::CompletedTask;
+
+use super::{
+    as_text,
+    tabular::{parse_csv_report, ReportColumns},
+    Importer,
+};
+
+const CLOCKIFY_COLUMNS: ReportColumns = ReportColumns {
+    name: &["Description", "Task"],
+    project: &["Project"],
+    tags: &["Tags"],
+    start_date: &["Start Date"],
+    start_time: &["Start Time"],
+    end_date: &["End Date"],
+    end_time: &["End Time"],
+};
+
+/// Clockify detailed report exported as CSV. Clockify writes dates in the date format of the
+/// workspace settings, the default one is `MM/DD/YYYY`
+pub struct ClockifyImporter {
+    pub date_format: String,
+}
+
+impl Default for ClockifyImporter {
+    fn default() -> ClockifyImporter {
+        ClockifyImporter {
+            date_format: "%m/%d/%Y".to_owned(),
+        }
+    }
+}
+
+impl Importer for ClockifyImporter {
+    fn name(&self) -> &'static str {
+        "clockify"
+    }
+
+    fn parse(&self, content: &[u8]) -> Result<Vec<CompletedTask>, String> {
+        let content = as_text(content)?;
+
+        parse_csv_report(content, &CLOCKIFY_COLUMNS, &self.date_format, "Clockify")
+    }
+}
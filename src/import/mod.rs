@@ -0,0 +1,213 @@
+pub mod clockify;
+pub mod org;
+pub mod tabular;
+pub mod timewarrior;
+pub mod toggl;
+pub mod watson;
+pub mod xlsx;
+
+use std::collections::HashSet;
+
+use itertools::Itertools;
+
+use crate::{
+    history::History,
+    report::{task_hours, ReportDay},
+    task::CompletedTask,
+};
+
+pub use self::{
+    clockify::ClockifyImporter, org::OrgImporter, timewarrior::TimewarriorImporter,
+    toggl::TogglImporter, watson::WatsonImporter, xlsx::XlsxImporter,
+};
+
+pub trait Importer {
+    /// Name used on the command line, e.g. `toggl`
+    fn name(&self) -> &'static str;
+
+    /// Reads the content of an exported file into completed tasks
+    fn parse(&self, content: &[u8]) -> Result<Vec<CompletedTask>, String>;
+}
+
+/// Content of the text formats
+pub fn as_text(content: &[u8]) -> Result<&str, String> {
+    std::str::from_utf8(content).map_err(|_| "File is not valid UTF-8 text".to_owned())
+}
+
+/// What an import would change in the history
+pub struct ImportPlan {
+    pub new_tasks: Vec<CompletedTask>,
+    pub duplicates_count: usize,
+}
+
+/// `date_format` overrides the date format of the CSV reports, which otherwise follows the
+/// default export settings of each tool
+pub fn registry(date_format: Option<&str>) -> Vec<Box<dyn Importer>> {
+    let mut toggl_importer = TogglImporter::default();
+    let mut clockify_importer = ClockifyImporter::default();
+
+    if let Some(date_format) = date_format {
+        toggl_importer.date_format = date_format.to_owned();
+        clockify_importer.date_format = date_format.to_owned();
+    }
+
+    vec![
+        Box::new(TimewarriorImporter),
+        Box::new(OrgImporter),
+        Box::new(toggl_importer),
+        Box::new(clockify_importer),
+        Box::new(WatsonImporter),
+        Box::new(XlsxImporter),
+    ]
+}
+
+pub fn find_importer<'a>(
+    importers: &'a [Box<dyn Importer>],
+    format: &str,
+) -> Result<&'a dyn Importer, String> {
+    importers
+        .iter()
+        .find(|importer| importer.name() == format)
+        .map(|importer| importer.as_ref())
+        .ok_or(format!(
+            "Unknown import format: {}. Available: {}",
+            format,
+            importers.iter().map(|importer| importer.name()).join(", ")
+        ))
+}
+
+impl ImportPlan {
+    /// Tasks are identified by their start, end and name, so repeated rows and tasks already in the
+    /// history are skipped
+    pub fn prepare(history: &History, tasks: Vec<CompletedTask>) -> Result<ImportPlan, String> {
+        let mut known_ids: HashSet<String> = HashSet::new();
+
+        for date in tasks.iter().map(|task| task.dt_start.date_naive()).unique() {
+            if let Some(day) = history.load_day(date)? {
+                known_ids.extend(day.tasks.iter().map(|task| task.id()));
+            }
+        }
+
+        let tasks_count = tasks.len();
+
+        let new_tasks: Vec<CompletedTask> = tasks
+            .into_iter()
+            .filter(|task| task.dt_end > task.dt_start)
+            .filter(|task| known_ids.insert(task.id()))
+            .collect();
+
+        Ok(ImportPlan {
+            duplicates_count: tasks_count - new_tasks.len(),
+            new_tasks,
+        })
+    }
+
+    pub fn summary(&self) -> String {
+        let days = ReportDay::from_tasks(self.new_tasks.to_vec());
+        let hours: f64 = self.new_tasks.iter().map(task_hours).sum();
+
+        let mut lines = vec![format!(
+            "{} new tasks ({:.2} hours) in {} days, {} duplicates or empty entries skipped",
+            self.new_tasks.len(),
+            hours,
+            days.len(),
+            self.duplicates_count
+        )];
+
+        lines.extend(days.iter().map(|day| {
+            format!(
+                "  {}: {} tasks, {:.2} hours",
+                day.date.format("%d.%m.%Y"),
+                day.tasks.len(),
+                day.total_hours()
+            )
+        }));
+
+        lines.join("\n")
+    }
+
+    /// Archives the new tasks day by day
+    pub fn apply(self, history: &History) -> Result<String, String> {
+        let tasks_count = self.new_tasks.len();
+        let days = ReportDay::from_tasks(self.new_tasks);
+
+        for day in &days {
+            history.archive(day)?;
+        }
+
+        Ok(format!(
+            "Imported {} tasks into {} days",
+            tasks_count,
+            days.len()
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::prelude::*;
+
+    use super::*;
+
+    const CLOCKIFY_REPORT: &str = "Project,Description,Tags,Start Date,Start Time,End Date,End Time
+Backend,Code review,\"review, team\",03/04/2024,09:00:00,03/04/2024,10:30:00
+";
+
+    const TOGGL_REPORT: &str = "Project,Description,Tags,Start date,Start time,End date,End time
+Backend,Code review,,03/04/2024,09:00:00,03/04/2024,10:30:00
+";
+
+    fn parse(
+        date_format: Option<&str>,
+        format: &str,
+        content: &str,
+    ) -> Result<Vec<CompletedTask>, String> {
+        let importers = registry(date_format);
+
+        find_importer(&importers, format)?.parse(content.as_bytes())
+    }
+
+    #[test]
+    fn clockify_reads_month_first_dates_by_default() {
+        let tasks = parse(None, "clockify", CLOCKIFY_REPORT).unwrap();
+
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(
+            tasks[0].dt_start.naive_local(),
+            NaiveDate::from_ymd_opt(2024, 3, 4)
+                .unwrap()
+                .and_hms_opt(9, 0, 0)
+                .unwrap()
+        );
+        assert_eq!(tasks[0].name, "Code review");
+        assert_eq!(tasks[0].tag.as_deref(), Some("review"));
+        assert_eq!(tasks[0].project.as_deref(), Some("Backend"));
+    }
+
+    #[test]
+    fn date_format_option_applies_to_clockify_and_toggl() {
+        for (format, content) in [("clockify", CLOCKIFY_REPORT), ("toggl", TOGGL_REPORT)] {
+            let tasks = parse(Some("%d/%m/%Y"), format, content).unwrap();
+
+            assert_eq!(
+                tasks[0].dt_end.naive_local(),
+                NaiveDate::from_ymd_opt(2024, 4, 3)
+                    .unwrap()
+                    .and_hms_opt(10, 30, 0)
+                    .unwrap(),
+                "{}",
+                format
+            );
+        }
+    }
+
+    #[test]
+    fn toggl_rejects_dates_that_do_not_match_the_format() {
+        let Err(err) = parse(None, "toggl", TOGGL_REPORT) else {
+            panic!("ISO dates are expected by default");
+        };
+
+        assert!(err.contains("%Y-%m-%d"), "{}", err);
+        assert!(err.contains("--date-format"), "{}", err);
+    }
+}
@@ -0,0 +1,198 @@
+use chrono::prelude::*;
+use regex::Regex;
+
+use crate::{
+    export::org::{as_org_tag, ORG_COMMENT_PROPERTY, ORG_PROJECT_PROPERTY, ORG_TAG_PROPERTY},
+    task::CompletedTask,
+};
+
+use super::{as_text, Importer};
+
+const ORG_DEFAULT_TASK_NAME: &str = "Org";
+
+/// Heading the clock lines below it belong to
+#[derive(Default)]
+struct OrgHeading {
+    name: String,
+    org_tags: Vec<String>,
+    tag: Option<String>,
+    project: Option<String>,
+    comment: Option<String>,
+}
+
+pub struct OrgImporter;
+
+impl Importer for OrgImporter {
+    fn name(&self) -> &'static str {
+        "org"
+    }
+
+    fn parse(&self, content: &[u8]) -> Result<Vec<CompletedTask>, String> {
+        let content = as_text(content)?;
+
+        parse_org_clock_lines(content)
+    }
+}
+
+/// Reads closed `CLOCK:` lines, the task name and tag come from the heading above them.
+/// The `TAG`, `PROJECT` and `COMMENT` properties win over the org tags
+fn parse_org_clock_lines(content: &str) -> Result<Vec<CompletedTask>, String> {
+    let heading_regex = Regex::new(r"^\*+\s+(?:(?:TODO|DONE)\s+)?(.*?)(?:\s+:([^\s]+):)?\s*$")
+        .map_err(|_| "Could not build org heading pattern".to_owned())?;
+    let property_regex = Regex::new(r"^\s*:([A-Za-z_]+):\s*(.*?)\s*$")
+        .map_err(|_| "Could not build org property pattern".to_owned())?;
+    let clock_regex = Regex::new(
+        r"^\s*CLOCK:\s*\[(\d{4}-\d{2}-\d{2})[^\]]*?(\d{1,2}:\d{2})\]--\[(\d{4}-\d{2}-\d{2})[^\]]*?(\d{1,2}:\d{2})\]",
+    )
+    .map_err(|_| "Could not build org clock pattern".to_owned())?;
+
+    let mut heading = OrgHeading::default();
+    let mut tasks = vec![];
+
+    for (line_idx, line) in content.lines().enumerate() {
+        if let Some(captures) = heading_regex.captures(line) {
+            heading = OrgHeading {
+                name: captures[1].to_owned(),
+                org_tags: captures
+                    .get(2)
+                    .map(|tags| {
+                        tags.as_str()
+                            .split(':')
+                            .filter(|tag| !tag.is_empty())
+                            .map(|tag| tag.to_owned())
+                            .collect()
+                    })
+                    .unwrap_or_default(),
+                ..Default::default()
+            };
+
+            continue;
+        }
+
+        if let Some(captures) = clock_regex.captures(line) {
+            let dt_start = parse_org_date_time(&captures[1], &captures[2]);
+            let dt_end = parse_org_date_time(&captures[3], &captures[4]);
+
+            let (Some(dt_start), Some(dt_end)) = (dt_start, dt_end) else {
+                return Err(format!("Line {} has an invalid clock", line_idx + 1));
+            };
+
+            let name_without_comment = if heading.name.is_empty() {
+                ORG_DEFAULT_TASK_NAME.to_owned()
+            } else {
+                heading.name.to_owned()
+            };
+
+            let name = match &heading.comment {
+                Some(comment) => format!("{}. {}", name_without_comment, comment),
+                None => name_without_comment,
+            };
+
+            tasks.push(CompletedTask {
+                name,
+                dt_start,
+                dt_end,
+                end_comment: heading.comment.to_owned(),
+                issue: None,
+                tag: heading.tag.to_owned().or(tag_from_org_tags(&heading)),
+                project: heading.project.to_owned(),
+                plan_item: None,
+            });
+
+            continue;
+        }
+
+        if let Some(captures) = property_regex.captures(line) {
+            let value = Some(captures[2].to_owned()).filter(|value| !value.is_empty());
+
+            match &captures[1] {
+                ORG_TAG_PROPERTY => heading.tag = value,
+                ORG_PROJECT_PROPERTY => heading.project = value,
+                ORG_COMMENT_PROPERTY => heading.comment = value,
+                _ => {}
+            }
+        }
+    }
+
+    Ok(tasks)
+}
+
+fn parse_org_date_time(date: &str, time: &str) -> Option<DateTime<Local>> {
+    NaiveDateTime::parse_from_str(&format!("{} {}", date, time), "%Y-%m-%d %H:%M")
+        .ok()
+        .and_then(|dt| dt.and_local_timezone(Local).earliest())
+}
+
+/// The exported project is an org tag as well, so it is not taken as the task tag
+fn tag_from_org_tags(heading: &OrgHeading) -> Option<String> {
+    let project_org_tag = heading.project.as_deref().map(as_org_tag);
+
+    heading
+        .org_tags
+        .iter()
+        .find(|tag| project_org_tag.as_ref() != Some(*tag))
+        .cloned()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::read_to_string;
+
+    use super::*;
+    use crate::{
+        export::{org::OrgExporter, Exporter},
+        report::{Report, ReportDay},
+        utils::{test_dir, test_task as task},
+    };
+
+    #[test]
+    fn exported_tasks_are_imported_back() {
+        let dir = test_dir("org-round-trip");
+
+        let tasks = vec![
+            task(
+                "Review parser",
+                Some("code review"),
+                Some("Time Manager"),
+                Some("left notes"),
+            ),
+            task("Standup", None, Some("Team"), None),
+        ];
+
+        let report = Report::from_days(ReportDay::from_tasks(tasks.to_vec()));
+
+        let file_path = OrgExporter.export(&report, &dir).unwrap();
+        let imported = parse_org_clock_lines(&read_to_string(file_path).unwrap()).unwrap();
+
+        assert_eq!(imported.len(), tasks.len());
+
+        for task in &tasks {
+            let imported_task = imported
+                .iter()
+                .find(|imported_task| imported_task.name == task.name)
+                .unwrap();
+
+            assert_eq!(imported_task.id(), task.id());
+            assert_eq!(imported_task.end_comment, task.end_comment);
+            assert_eq!(imported_task.tag, task.tag);
+            assert_eq!(imported_task.project, task.project);
+        }
+    }
+
+    #[test]
+    fn org_tags_are_used_without_properties() {
+        let content = "* Notes
+** TODO Write docs :writing:Docs:
+:LOGBOOK:
+CLOCK: [2026-10-19 Mon 09:00]--[2026-10-19 Mon 10:15] =>  1:15
+:END:
+";
+
+        let tasks = parse_org_clock_lines(content).unwrap();
+
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0].name, "Write docs");
+        assert_eq!(tasks[0].tag.as_deref(), Some("writing"));
+        assert_eq!(tasks[0].duration().num_minutes(), 75);
+    }
+}
@@ -0,0 +1,117 @@
+use chrono::prelude::*;
+
+use crate::{task::CompletedTask, utils::parse_csv};
+
+const TIME_FORMATS: [&str; 4] = ["%H:%M:%S", "%I:%M:%S %p", "%H:%M", "%I:%M %p"];
+
+/// Header names of a CSV report, the first header found in the file is used
+pub struct ReportColumns {
+    pub name: &'static [&'static str],
+    pub project: &'static [&'static str],
+    pub tags: &'static [&'static str],
+    pub start_date: &'static [&'static str],
+    pub start_time: &'static [&'static str],
+    pub end_date: &'static [&'static str],
+    pub end_time: &'static [&'static str],
+}
+
+/// Reads a CSV report of time entries with separate date and time columns.
+/// All dates are read with `date_format`, since a day and a month can not be told apart in
+/// dates like 03/04/2024. Only the first of comma separated tags is kept
+pub fn parse_csv_report(
+    content: &str,
+    columns: &ReportColumns,
+    date_format: &str,
+    default_name: &str,
+) -> Result<Vec<CompletedTask>, String> {
+    let mut records = parse_csv(content, ',').into_iter();
+
+    let headers: Vec<String> = match records.next() {
+        Some(headers) => headers
+            .iter()
+            .map(|header| header.trim().to_owned())
+            .collect(),
+        None => return Ok(vec![]),
+    };
+
+    let column_idx = |names: &[&str]| {
+        names
+            .iter()
+            .find_map(|name| headers.iter().position(|header| header == name))
+    };
+
+    let required_column_idx = |names: &[&str]| {
+        column_idx(names).ok_or(format!("Column {} is missing", names.join(" or ")))
+    };
+
+    let name_idx = column_idx(columns.name);
+    let project_idx = column_idx(columns.project);
+    let tags_idx = column_idx(columns.tags);
+    let start_date_idx = required_column_idx(columns.start_date)?;
+    let start_time_idx = required_column_idx(columns.start_time)?;
+    let end_date_idx = required_column_idx(columns.end_date)?;
+    let end_time_idx = required_column_idx(columns.end_time)?;
+
+    let mut tasks = vec![];
+
+    for (row_idx, record) in records.enumerate() {
+        let field = |idx: Option<usize>| {
+            idx.and_then(|idx| record.get(idx))
+                .map(|value| value.trim().to_owned())
+                .filter(|value| !value.is_empty())
+        };
+
+        let dt_start = parse_date_time(
+            field(Some(start_date_idx)),
+            field(Some(start_time_idx)),
+            date_format,
+        );
+        let dt_end = parse_date_time(
+            field(Some(end_date_idx)),
+            field(Some(end_time_idx)),
+            date_format,
+        );
+
+        let (Some(dt_start), Some(dt_end)) = (dt_start, dt_end) else {
+            return Err(format!(
+                "Row {} has an invalid start or end, dates are expected as {} (see --date-format)",
+                row_idx + 2,
+                date_format
+            ));
+        };
+
+        let tag = field(tags_idx).and_then(|tags| {
+            tags.split(',')
+                .map(|tag| tag.trim().to_owned())
+                .find(|tag| !tag.is_empty())
+        });
+
+        tasks.push(CompletedTask {
+            name: field(name_idx).unwrap_or(default_name.to_owned()),
+            dt_start,
+            dt_end,
+            end_comment: None,
+            issue: None,
+            tag,
+            project: field(project_idx),
+            plan_item: None,
+        });
+    }
+
+    Ok(tasks)
+}
+
+fn parse_date_time(
+    date: Option<String>,
+    time: Option<String>,
+    date_format: &str,
+) -> Option<DateTime<Local>> {
+    let (date, time) = (date?, time?);
+
+    let date = NaiveDate::parse_from_str(&date, date_format).ok()?;
+    let time = TIME_FORMATS
+        .iter()
+        .find_map(|format| NaiveTime::parse_from_str(&time, format).ok())?;
+
+    date.and_time(time).and_local_timezone(Local).earliest()
+}
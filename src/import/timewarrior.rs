@@ -0,0 +1,244 @@
+use chrono::prelude::*;
+
+use crate::{
+    export::timewarrior::{TIMEWARRIOR_DATE_TIME_FORMAT, TIMEWARRIOR_PROJECT_TAG_PREFIX},
+    task::CompletedTask,
+};
+
+use super::{as_text, Importer};
+
+const TIMEWARRIOR_DEFAULT_TASK_NAME: &str = "Timewarrior";
+
+#[derive(PartialEq)]
+enum Token {
+    Word(String),
+    Separator,
+}
+
+pub struct TimewarriorImporter;
+
+impl Importer for TimewarriorImporter {
+    fn name(&self) -> &'static str {
+        "timewarrior"
+    }
+
+    fn parse(&self, content: &[u8]) -> Result<Vec<CompletedTask>, String> {
+        let content = as_text(content)?;
+
+        parse_timewarrior_data(content)
+    }
+}
+
+/// Reads `inc` lines of a Timewarrior `.data` file, open intervals are skipped.
+/// The first tag becomes the task name, the second one the task tag, a `project:` tag the project
+/// and the annotation the end comment, as the exporter writes them
+fn parse_timewarrior_data(content: &str) -> Result<Vec<CompletedTask>, String> {
+    let mut tasks = vec![];
+
+    for (line_idx, line) in content.lines().enumerate() {
+        let line = line.trim();
+
+        if !line.starts_with("inc ") {
+            continue;
+        }
+
+        let tokens = tokenize(&line[4..]);
+        let mut tokens = tokens.split(|token| *token == Token::Separator);
+
+        let interval: Vec<&str> = tokens
+            .next()
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|token| match token {
+                Token::Word(word) => Some(word.as_str()),
+                Token::Separator => None,
+            })
+            .collect();
+
+        let (dt_start, dt_end) = match interval.as_slice() {
+            [start, "-", end] => (parse_date_time(start), parse_date_time(end)),
+            [_] => continue,
+            _ => return Err(format!("Line {} is not a valid interval", line_idx + 1)),
+        };
+
+        let (Some(dt_start), Some(dt_end)) = (dt_start, dt_end) else {
+            return Err(format!("Line {} has an invalid date", line_idx + 1));
+        };
+
+        let words = |tokens: Option<&[Token]>| -> Vec<String> {
+            tokens
+                .unwrap_or_default()
+                .iter()
+                .filter_map(|token| match token {
+                    Token::Word(word) => Some(word.to_owned()),
+                    Token::Separator => None,
+                })
+                .collect()
+        };
+
+        let tags = words(tokens.next());
+        let annotation = words(tokens.next()).join(" ");
+
+        let project = tags.iter().find_map(|tag| {
+            tag.strip_prefix(TIMEWARRIOR_PROJECT_TAG_PREFIX)
+                .map(|project| project.to_owned())
+        });
+        let mut labels = tags
+            .iter()
+            .filter(|tag| !tag.starts_with(TIMEWARRIOR_PROJECT_TAG_PREFIX))
+            .cloned();
+
+        let name_without_comment = labels
+            .next()
+            .unwrap_or(TIMEWARRIOR_DEFAULT_TASK_NAME.to_owned());
+        let tag = labels.next();
+
+        let end_comment = Some(annotation).filter(|annotation| !annotation.is_empty());
+
+        // Tracked tasks keep the end comment appended to the name, imported ones do the same
+        let name = match &end_comment {
+            Some(comment) => format!("{}. {}", name_without_comment, comment),
+            None => name_without_comment,
+        };
+
+        tasks.push(CompletedTask {
+            name,
+            dt_start,
+            dt_end,
+            end_comment,
+            issue: None,
+            tag,
+            project,
+            plan_item: None,
+        });
+    }
+
+    Ok(tasks)
+}
+
+fn parse_date_time(value: &str) -> Option<DateTime<Local>> {
+    NaiveDateTime::parse_from_str(value, TIMEWARRIOR_DATE_TIME_FORMAT)
+        .ok()
+        .map(|dt| Utc.from_utc_datetime(&dt).with_timezone(&Local))
+}
+
+/// Splits on whitespace, keeps quoted strings together and turns a lone `#` into a separator
+fn tokenize(input: &str) -> Vec<Token> {
+    let mut tokens = vec![];
+    let mut chars = input.chars().peekable();
+
+    while let Some(char) = chars.next() {
+        match char {
+            char if char.is_whitespace() => {}
+            '"' => {
+                let mut word = String::new();
+
+                while let Some(char) = chars.next() {
+                    match char {
+                        '\\' => {
+                            if let Some(escaped) = chars.next() {
+                                word.push(escaped);
+                            }
+                        }
+                        '"' => break,
+                        char => word.push(char),
+                    }
+                }
+
+                tokens.push(Token::Word(word));
+            }
+            '#' if chars.peek().is_none_or(|next| next.is_whitespace()) => {
+                tokens.push(Token::Separator)
+            }
+            char => {
+                let mut word = char.to_string();
+
+                while let Some(next) = chars.peek() {
+                    if next.is_whitespace() {
+                        break;
+                    }
+
+                    word.push(*next);
+                    chars.next();
+                }
+
+                tokens.push(Token::Word(word));
+            }
+        }
+    }
+
+    tokens
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::read_to_string;
+
+    use super::*;
+    use crate::{
+        export::{timewarrior::TimewarriorExporter, Exporter},
+        report::{Report, ReportDay},
+        utils::{test_dir, test_task as task},
+    };
+
+    #[test]
+    fn exported_tasks_are_imported_back() {
+        let dir = test_dir("timewarrior-round-trip");
+
+        let tasks = vec![
+            task(
+                "Review \"parser\" #12",
+                Some("review"),
+                Some("Time Manager"),
+                Some("left notes # for later"),
+            ),
+            task("Standup", None, None, None),
+        ];
+
+        let exporter = TimewarriorExporter {
+            data_dir: Some(dir.to_owned()),
+        };
+        let report = Report::from_days(ReportDay::from_tasks(tasks.to_vec()));
+
+        let file_path = exporter.export(&report, &dir).unwrap();
+        let imported = parse_timewarrior_data(&read_to_string(file_path).unwrap()).unwrap();
+
+        assert_eq!(imported.len(), tasks.len());
+
+        for task in &tasks {
+            let imported_task = imported
+                .iter()
+                .find(|imported_task| imported_task.name == task.name)
+                .unwrap();
+
+            assert_eq!(imported_task.id(), task.id());
+            assert_eq!(imported_task.end_comment, task.end_comment);
+            assert_eq!(imported_task.tag, task.tag);
+            assert_eq!(imported_task.project, task.project);
+        }
+    }
+
+    #[test]
+    fn snapshot_is_not_merged_into_data_dir() {
+        let dir = test_dir("timewarrior-snapshot");
+        let data_dir = dir.join("data");
+
+        let exporter = TimewarriorExporter {
+            data_dir: Some(data_dir.to_owned()),
+        };
+        let day = ReportDay::from_tasks(vec![task("Standup", None, None, None)]);
+        let report = Report::snapshot(day.into_iter().next().unwrap());
+
+        let file_path = exporter.export(&report, &dir).unwrap();
+
+        assert!(file_path.ends_with(".snapshot.data"), "{}", file_path);
+        assert!(!data_dir.exists());
+    }
+
+    #[test]
+    fn open_intervals_are_skipped() {
+        let tasks = parse_timewarrior_data("inc 20261019T090000Z # Standup\n").unwrap();
+
+        assert!(tasks.is_empty());
+    }
+}
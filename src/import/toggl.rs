@@ -0,0 +1,42 @@
+use crate::task::CompletedTask;
+
+use super::{
+    as_text,
+    tabular::{parse_csv_report, ReportColumns},
+    Importer,
+};
+
+const TOGGL_COLUMNS: ReportColumns = ReportColumns {
+    name: &["Description", "Task"],
+    project: &["Project"],
+    tags: &["Tags"],
+    start_date: &["Start date", "Start Date"],
+    start_time: &["Start time", "Start Time"],
+    end_date: &["End date", "End Date"],
+    end_time: &["End time", "End Time"],
+};
+
+/// Toggl Track detailed report exported as CSV, Toggl always writes ISO dates
+pub struct TogglImporter {
+    pub date_format: String,
+}
+
+impl Default for TogglImporter {
+    fn default() -> TogglImporter {
+        TogglImporter {
+            date_format: "%Y-%m-%d".to_owned(),
+        }
+    }
+}
+
+impl Importer for TogglImporter {
+    fn name(&self) -> &'static str {
+        "toggl"
+    }
+
+    fn parse(&self, content: &[u8]) -> Result<Vec<CompletedTask>, String> {
+        let content = as_text(content)?;
+
+        parse_csv_report(content, &TOGGL_COLUMNS, &self.date_format, "Toggl")
+    }
+}
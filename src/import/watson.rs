@@ -0,0 +1,70 @@
+use chrono::prelude::*;
+use serde::de::IgnoredAny;
+
+use crate::task::CompletedTask;
+
+use super::Importer;
+
+/// One frame of the Watson `frames` file: `[start, stop, project, id, tags, updated_at]`
+type WatsonFrame = (i64, i64, String, IgnoredAny, Vec<String>, IgnoredAny);
+
+/// Watson `frames` JSON file, the project becomes the task name and the first tag the task tag
+pub struct WatsonImporter;
+
+impl Importer for WatsonImporter {
+    fn name(&self) -> &'static str {
+        "watson"
+    }
+
+    fn parse(&self, content: &[u8]) -> Result<Vec<CompletedTask>, String> {
+        let frames: Vec<WatsonFrame> = serde_json::from_slice(content)
+            .map_err(|_| "Watson frames file is not valid JSON".to_owned())?;
+
+        frames
+            .into_iter()
+            .map(|(start, stop, project, _, tags, _)| {
+                let (Some(dt_start), Some(dt_end)) = (
+                    Local.timestamp_opt(start, 0).single(),
+                    Local.timestamp_opt(stop, 0).single(),
+                ) else {
+                    return Err(format!("Frame of {} has an invalid time", project));
+                };
+
+                Ok(CompletedTask {
+                    name: project.to_owned(),
+                    dt_start,
+                    dt_end,
+                    end_comment: None,
+                    issue: None,
+                    tag: tags.first().cloned(),
+                    project: Some(project),
+                    plan_item: None,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frames_become_tasks_named_after_the_project() {
+        let content =
+            r#"[[1760864400, 1760868000, "time-manager", "a1b2", ["review", "rust"], 1760868001]]"#;
+
+        let tasks = WatsonImporter.parse(content.as_bytes()).unwrap();
+
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0].name, "time-manager");
+        assert_eq!(tasks[0].project.as_deref(), Some("time-manager"));
+        assert_eq!(tasks[0].tag.as_deref(), Some("review"));
+        assert_eq!(tasks[0].duration().num_minutes(), 60);
+    }
+
+    #[test]
+    fn invalid_json_is_reported() {
+        assert!(WatsonImporter.parse(b"{}").is_err());
+    }
+}
@@ -0,0 +1,128 @@
+use std::io::Cursor;
+
+use calamine::{open_workbook_from_rs, Data, Reader, Xlsx};
+use chrono::{prelude::*, Duration};
+
+use crate::task::CompletedTask;
+
+use super::Importer;
+
+const SHEET_DATE_FORMAT: &str = "%d.%m.%Y";
+const SECONDS_IN_DAY: f64 = 86400_f64;
+
+const TAG_COLUMN: usize = 1;
+const NAME_COLUMN: usize = 2;
+const START_COLUMN: usize = 3;
+const END_COLUMN: usize = 4;
+
+/// Day reports written by the xlsx export: the sheet name is the date,
+/// columns B–E hold tag, name, start and end.
+/// The date in A1 shares the first row with the first task in old reports and
+/// new reports have a title and header rows, so the tasks are the first run of
+/// rows with a name and valid times. The total, tag and plan sections after them are skipped
+pub struct XlsxImporter;
+
+impl Importer for XlsxImporter {
+    fn name(&self) -> &'static str {
+        "xlsx"
+    }
+
+    fn parse(&self, content: &[u8]) -> Result<Vec<CompletedTask>, String> {
+        let mut workbook: Xlsx<_> = open_workbook_from_rs(Cursor::new(content))
+            .map_err(|_| "File is not a valid xlsx workbook".to_owned())?;
+
+        let mut tasks = vec![];
+
+        for (sheet_name, range) in workbook.worksheets() {
+            let Ok(date) = NaiveDate::parse_from_str(sheet_name.trim(), SHEET_DATE_FORMAT) else {
+                continue;
+            };
+
+            // The range starts at the first used cell, which is not always in column A
+            let first_column = range
+                .start()
+                .map(|(_, column)| column as usize)
+                .unwrap_or(0);
+
+            let mut sheet_tasks: Vec<CompletedTask> = vec![];
+
+            for row in range.rows() {
+                let cell = |idx: usize| idx.checked_sub(first_column).and_then(|idx| row.get(idx));
+
+                match row_as_task(cell, date) {
+                    Some(task) => sheet_tasks.push(task),
+                    None if !sheet_tasks.is_empty() => break,
+                    None => {}
+                }
+            }
+
+            tasks.extend(sheet_tasks);
+        }
+
+        Ok(tasks)
+    }
+}
+
+fn row_as_task<'a>(
+    cell: impl Fn(usize) -> Option<&'a Data>,
+    date: NaiveDate,
+) -> Option<CompletedTask> {
+    let text = |idx: usize| match cell(idx) {
+        Some(Data::String(value)) => {
+            Some(value.trim().to_owned()).filter(|value| !value.is_empty())
+        }
+        _ => None,
+    };
+
+    let name = text(NAME_COLUMN)?;
+    let start_time = cell_as_time(cell(START_COLUMN)?)?;
+    let end_time = cell_as_time(cell(END_COLUMN)?)?;
+
+    let dt_start = date
+        .and_time(start_time)
+        .and_local_timezone(Local)
+        .earliest()?;
+    let mut dt_end = date
+        .and_time(end_time)
+        .and_local_timezone(Local)
+        .earliest()?;
+
+    // Tasks that went past midnight
+    if dt_end < dt_start {
+        dt_end += Duration::days(1);
+    }
+
+    Some(CompletedTask {
+        name,
+        dt_start,
+        dt_end,
+        end_comment: None,
+        issue: None,
+        tag: text(TAG_COLUMN),
+        project: None,
+        plan_item: None,
+    })
+}
+
+/// Times are stored as a fraction of a day, text like `09:30` is accepted as well
+fn cell_as_time(cell: &Data) -> Option<NaiveTime> {
+    let day_fraction = match cell {
+        Data::Float(value) => *value,
+        Data::Int(value) => *value as f64,
+        Data::DateTime(value) => value.as_f64(),
+        Data::String(value) => {
+            return NaiveTime::parse_from_str(value.trim(), "%H:%M:%S")
+                .or_else(|_| NaiveTime::parse_from_str(value.trim(), "%H:%M"))
+                .ok()
+        }
+        _ => return None,
+    };
+
+    if !(0_f64..1_f64).contains(&day_fraction) {
+        return None;
+    }
+
+    let seconds = (day_fraction * SECONDS_IN_DAY).round() as u32;
+
+    NaiveTime::from_num_seconds_from_midnight_opt(seconds.min(86399), 0)
+}
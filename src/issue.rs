@@ -0,0 +1,31 @@
+use regex::Regex;
+
+#[derive(Default)]
+pub struct IssueKeyMatcher {
+    patterns: Vec<Regex>,
+}
+
+impl IssueKeyMatcher {
+    pub fn init(patterns: &[String]) -> Result<IssueKeyMatcher, String> {
+        let mut compiled_patterns = vec![];
+
+        for pattern in patterns {
+            match Regex::new(pattern) {
+                Ok(regex) => compiled_patterns.push(regex),
+                Err(_) => return Err(format!("Invalid issue key pattern: {}", pattern)),
+            }
+        }
+
+        Ok(IssueKeyMatcher {
+            patterns: compiled_patterns,
+        })
+    }
+
+    /// Patterns are tried in the configured order, so more specific ones should go first
+    pub fn find_issue_key(&self, text: &str) -> Option<String> {
+        self.patterns
+            .iter()
+            .find_map(|pattern| pattern.find(text))
+            .map(|issue_key| issue_key.as_str().to_owned())
+    }
+}
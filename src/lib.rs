@@ -1,6 +1,20 @@
 pub mod app;
+pub mod cli;
+pub mod config;
+pub mod export;
+pub mod history;
+pub mod import;
+pub mod issue;
+pub mod paths;
+pub mod plan;
+pub mod report;
 pub mod state;
+pub mod summary;
+pub mod tag_model;
 pub mod task;
+pub mod templates;
 pub mod theme;
+pub mod time_expression;
 pub mod ui;
 pub mod utils;
+pub mod worklog_upload;
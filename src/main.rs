@@ -5,20 +5,26 @@ use crossterm::{
 
 use anyhow::Result;
 
-use std::{env, io::stdout, path::PathBuf};
+use std::{env, io::stdout};
 
-use time_manager::app::App;
-
-const STATE_FILE_NAME: &str = "state.json";
+use time_manager::{app::App, cli, paths::AppPaths, utils::get_executable_dir};
 
 fn main() -> Result<()> {
-    initialize_panic_handler();
+    let app_paths = AppPaths::in_dir(&get_executable_dir());
+
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    if !args.is_empty() {
+        let message = cli::run(&args, &app_paths).map_err(anyhow::Error::msg)?;
 
-    let current_exe_path = env::current_exe().unwrap();
-    let current_dir_path = current_exe_path.parent().unwrap();
-    let state_file_path: PathBuf = current_dir_path.join(STATE_FILE_NAME);
+        println!("{}", message);
+
+        return Ok(());
+    }
+
+    initialize_panic_handler();
 
-    let app = App::init(&state_file_path);
+    let app = App::init(&app_paths);
 
     startup()?;
 
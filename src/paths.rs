@@ -0,0 +1,35 @@
+use std::path::{Path, PathBuf};
+
+const STATE_FILE_NAME: &str = "state.json";
+const CONFIG_FILE_NAME: &str = "config.json";
+const TEMPLATES_FILE_NAME: &str = "templates.json";
+const HISTORY_DIR_NAME: &str = "history";
+const SENT_WORKLOGS_FILE_NAME: &str = "sent_worklogs.json";
+const TAG_CACHE_FILE_NAME: &str = "tag_cache.json";
+const TAG_CORRECTIONS_FILE_NAME: &str = "tag_corrections.csv";
+
+pub struct AppPaths {
+    pub state_file: PathBuf,
+    pub config_file: PathBuf,
+    pub templates_file: PathBuf,
+    pub history_dir: PathBuf,
+    pub sent_worklogs_file: PathBuf,
+    pub tag_cache_file: PathBuf,
+    pub tag_corrections_file: PathBuf,
+    pub output_dir: PathBuf,
+}
+
+impl AppPaths {
+    pub fn in_dir(dir: &Path) -> AppPaths {
+        AppPaths {
+            state_file: dir.join(STATE_FILE_NAME),
+            config_file: dir.join(CONFIG_FILE_NAME),
+            templates_file: dir.join(TEMPLATES_FILE_NAME),
+            history_dir: dir.join(HISTORY_DIR_NAME),
+            sent_worklogs_file: dir.join(SENT_WORKLOGS_FILE_NAME),
+            tag_cache_file: dir.join(TAG_CACHE_FILE_NAME),
+            tag_corrections_file: dir.join(TAG_CORRECTIONS_FILE_NAME),
+            output_dir: dir.to_path_buf(),
+        }
+    }
+}
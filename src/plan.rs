@@ -0,0 +1,129 @@
+use chrono::Local;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    task::{CompletedTask, NotCompletedTask},
+    time_expression::parse_duration_minutes,
+};
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct PlannedItem {
+    pub id: usize,
+    pub name: String,
+    pub estimate_minutes: Option<u32>,
+}
+
+#[derive(Clone, Default, Serialize, Deserialize)]
+pub struct Plan {
+    pub items: Vec<PlannedItem>,
+}
+
+pub struct PlanReportRow {
+    pub name: String,
+    pub estimate_minutes: Option<u32>,
+    pub actual_minutes: i64,
+    pub is_planned: bool,
+}
+
+impl PlannedItem {
+    /// Parses `Task name ~ 1h30m`, the estimate after `~` is optional
+    pub fn parse(input: &str) -> Result<PlannedItem, String> {
+        let (name, estimate) = match input.rsplit_once('~') {
+            Some((name, estimate)) => (name.trim(), Some(estimate.trim())),
+            None => (input.trim(), None),
+        };
+
+        if name.is_empty() {
+            return Err("You should enter planned task name!".to_owned());
+        }
+
+        let estimate_minutes = match estimate {
+            Some(estimate) => Some(parse_duration_minutes(estimate)?),
+            None => None,
+        };
+
+        Ok(PlannedItem {
+            id: 0,
+            name: name.to_owned(),
+            estimate_minutes,
+        })
+    }
+
+    pub fn start_task(&self) -> NotCompletedTask {
+        let mut task = NotCompletedTask::start(self.name.to_owned());
+
+        task.planned_minutes = self.estimate_minutes;
+        task.plan_item = Some(self.id);
+
+        task
+    }
+}
+
+impl Plan {
+    /// Ids stay stable when items are removed, tasks refer to them
+    pub fn add_item(&mut self, mut item: PlannedItem) {
+        item.id = self.items.iter().map(|item| item.id + 1).max().unwrap_or(0);
+
+        self.items.push(item);
+    }
+
+    pub fn remove_item(&mut self, idx: usize) {
+        if idx < self.items.len() {
+            self.items.remove(idx);
+        }
+    }
+
+    /// One row per planned item followed by the unplanned work grouped by task name
+    pub fn compare_with_actual(
+        &self,
+        completed_tasks: &[CompletedTask],
+        current_task: Option<&NotCompletedTask>,
+    ) -> Vec<PlanReportRow> {
+        // Unplanned work is grouped by name, end comments would split one task into several rows
+        let mut tracked: Vec<(Option<usize>, String, i64)> = completed_tasks
+            .iter()
+            .map(|task| {
+                let minutes = (task.dt_end - task.dt_start).num_minutes();
+                (task.plan_item, task.name_without_comment(), minutes)
+            })
+            .collect();
+
+        if let Some(task) = current_task {
+            let minutes = (Local::now() - task.dt_start).num_minutes();
+            tracked.push((task.plan_item, task.name.to_owned(), minutes));
+        }
+
+        let mut rows: Vec<PlanReportRow> = self
+            .items
+            .iter()
+            .map(|item| PlanReportRow {
+                name: item.name.to_owned(),
+                estimate_minutes: item.estimate_minutes,
+                actual_minutes: tracked
+                    .iter()
+                    .filter(|(plan_item, _, _)| *plan_item == Some(item.id))
+                    .map(|(_, _, minutes)| minutes)
+                    .sum(),
+                is_planned: true,
+            })
+            .collect();
+
+        let unplanned_rows = tracked
+            .iter()
+            .filter(|(plan_item, _, _)| !self.items.iter().any(|item| Some(item.id) == *plan_item))
+            .into_group_map_by(|(_, name, _)| name.to_owned())
+            .into_iter()
+            .map(|(name, tasks)| PlanReportRow {
+                name,
+                estimate_minutes: None,
+                actual_minutes: tasks.iter().map(|(_, _, minutes)| minutes).sum(),
+                is_planned: false,
+            })
+            .sorted_by(|a, b| a.name.cmp(&b.name));
+
+        rows.extend(unplanned_rows);
+
+        rows
+    }
+}
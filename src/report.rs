@@ -0,0 +1,184 @@
+use chrono::prelude::*;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    issue::IssueKeyMatcher, plan::Plan, state::DailyState, tag_model::TagPredictor,
+    task::CompletedTask, utils::MILLISECONDS_IN_HOUR,
+};
+
+pub const NO_TAG: &str = "Untagged";
+pub const NO_PROJECT: &str = "No project";
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ReportDay {
+    pub date: NaiveDate,
+    pub start_time: DateTime<Local>,
+    pub end_time: Option<DateTime<Local>>,
+    pub tasks: Vec<CompletedTask>,
+    pub plan: Plan,
+}
+
+#[derive(Clone)]
+pub struct Report {
+    pub days: Vec<ReportDay>,
+    /// The day is not ended yet, files get a `.snapshot` suffix so End does not overwrite them
+    pub is_snapshot: bool,
+}
+
+pub fn task_hours(task: &CompletedTask) -> f64 {
+    (task.dt_end - task.dt_start).num_milliseconds() as f64 / MILLISECONDS_IN_HOUR
+}
+
+impl ReportDay {
+    pub fn from_daily_state(state: &DailyState) -> ReportDay {
+        let tasks = match state.completed_tasks.lock() {
+            Ok(tasks) => tasks.to_vec(),
+            Err(_) => vec![],
+        };
+
+        ReportDay {
+            date: state.start_time.date_naive(),
+            start_time: state.start_time,
+            end_time: state.end_time,
+            tasks,
+            plan: state.plan.clone(),
+        }
+    }
+
+    /// Groups tasks coming from other tools into days by their start date
+    pub fn from_tasks(tasks: Vec<CompletedTask>) -> Vec<ReportDay> {
+        tasks
+            .into_iter()
+            .into_group_map_by(|task| task.dt_start.date_naive())
+            .into_iter()
+            .map(|(date, tasks)| {
+                let tasks: Vec<CompletedTask> = tasks
+                    .into_iter()
+                    .sorted_by_key(|task| task.dt_start)
+                    .collect();
+
+                ReportDay {
+                    date,
+                    start_time: tasks[0].dt_start,
+                    end_time: tasks.iter().map(|task| task.dt_end).max(),
+                    tasks,
+                    plan: Plan::default(),
+                }
+            })
+            .sorted_by_key(|day| day.date)
+            .collect()
+    }
+
+    /// Day so far with the running task completed at `now`, the state itself is not changed
+    pub fn snapshot(
+        state: &DailyState,
+        now: DateTime<Local>,
+        issue_key_matcher: &IssueKeyMatcher,
+    ) -> ReportDay {
+        let mut day = ReportDay::from_daily_state(state);
+
+        if let Some(current_task) = &state.current_task {
+            let mut running_task = current_task.complete_task(None, now);
+            running_task.issue = issue_key_matcher.find_issue_key(&running_task.name);
+
+            day.tasks.push(running_task);
+        }
+
+        day.end_time = Some(now);
+
+        day
+    }
+
+    pub fn total_hours(&self) -> f64 {
+        self.tasks.iter().map(task_hours).sum()
+    }
+}
+
+impl Report {
+    pub fn from_days(days: Vec<ReportDay>) -> Report {
+        Report {
+            days: days.into_iter().sorted_by_key(|day| day.date).collect(),
+            is_snapshot: false,
+        }
+    }
+
+    pub fn snapshot(day: ReportDay) -> Report {
+        Report {
+            days: vec![day],
+            is_snapshot: true,
+        }
+    }
+
+    /// Tasks without a tag get the one predicted by the rules or the model, all at once
+    pub fn assign_predicted_tags(&mut self, tag_predictor: &TagPredictor) {
+        let untagged_tasks: Vec<&mut CompletedTask> = self
+            .days
+            .iter_mut()
+            .flat_map(|day| day.tasks.iter_mut())
+            .filter(|task| task.tag.is_none())
+            .collect();
+
+        let task_names: Vec<String> = untagged_tasks
+            .iter()
+            .map(|task| task.name.to_owned())
+            .collect();
+
+        for (task, tag) in untagged_tasks
+            .into_iter()
+            .zip(tag_predictor.predict_many(&task_names))
+        {
+            task.tag = tag;
+        }
+    }
+
+    pub fn tasks(&self) -> impl Iterator<Item = &CompletedTask> {
+        self.days.iter().flat_map(|day| day.tasks.iter())
+    }
+
+    pub fn total_hours(&self) -> f64 {
+        self.days.iter().map(|day| day.total_hours()).sum()
+    }
+
+    pub fn hours_by_tag(&self) -> Vec<(String, f64)> {
+        self.hours_by(|task| task.tag.to_owned().unwrap_or(NO_TAG.to_owned()))
+    }
+
+    pub fn hours_by_project(&self) -> Vec<(String, f64)> {
+        self.hours_by(|task| task.project.to_owned().unwrap_or(NO_PROJECT.to_owned()))
+    }
+
+    /// `dd.mm.yyyy` for a single day, `dd.mm.yyyy-dd.mm.yyyy` for a range and
+    /// `dd.mm.yyyy.snapshot` for a day that is not ended yet
+    pub fn file_stem(&self) -> String {
+        let first_day = self.days.first().map(|day| day.date);
+        let last_day = self.days.last().map(|day| day.date);
+
+        let stem = match (first_day, last_day) {
+            (Some(first_day), Some(last_day)) if first_day == last_day => {
+                first_day.format("%d.%m.%Y").to_string()
+            }
+            (Some(first_day), Some(last_day)) => format!(
+                "{}-{}",
+                first_day.format("%d.%m.%Y"),
+                last_day.format("%d.%m.%Y")
+            ),
+            _ => Local::now().format("%d.%m.%Y").to_string(),
+        };
+
+        if self.is_snapshot {
+            format!("{}.snapshot", stem)
+        } else {
+            stem
+        }
+    }
+
+    fn hours_by(&self, key: impl Fn(&CompletedTask) -> String) -> Vec<(String, f64)> {
+        self.tasks()
+            .into_group_map_by(|task| key(task))
+            .into_iter()
+            .map(|(key, tasks)| (key, tasks.into_iter().map(task_hours).sum()))
+            .sorted_by(|a: &(String, f64), b| a.0.cmp(&b.0))
+            .collect()
+    }
+}
@@ -1,14 +1,15 @@
-use std::env;
 use std::fs::{remove_file, File, OpenOptions};
 use std::io::prelude::*;
 use std::path::PathBuf;
 use std::sync::Mutex;
 
+use crate::history::History;
+use crate::issue::IssueKeyMatcher;
+use crate::plan::Plan;
+use crate::report::ReportDay;
 use crate::task::{CompletedTask, NotCompletedTask};
 
 use chrono::prelude::*;
-use ort::{inputs, Session, Value};
-use rust_xlsxwriter::{ExcelDateTime, Format, Formula, Workbook};
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize)]
@@ -17,6 +18,8 @@ pub struct DailyState {
     pub completed_tasks: Mutex<Vec<CompletedTask>>,
     pub start_time: DateTime<Local>,
     pub end_time: Option<DateTime<Local>>,
+    #[serde(default)]
+    pub plan: Plan,
 }
 
 impl DailyState {
@@ -24,6 +27,25 @@ impl DailyState {
         DailyState::fetch_or_init_state(file_path)
     }
 
+    /// Reads the state without creating or replacing the file, None when no day is started
+    pub fn read(file_path: &PathBuf) -> Result<Option<DailyState>, String> {
+        let mut string = String::new();
+
+        match File::open(file_path) {
+            Ok(mut file) => {
+                if file.read_to_string(&mut string).is_err() {
+                    return Err("Could not read state file".to_owned());
+                }
+            }
+            Err(_) => return Ok(None),
+        }
+
+        match serde_json::from_str(&string) {
+            Ok(state) => Ok(Some(state)),
+            Err(_) => Err("Could not parse state file".to_owned()),
+        }
+    }
+
     pub fn save(&self, file_path: &PathBuf) -> Result<String, String> {
         let result_with_string = serde_json::to_string(self);
 
@@ -61,11 +83,23 @@ impl DailyState {
         }
     }
 
-    pub fn clear_todays_state(&mut self, file_path: &PathBuf) -> Result<String, String> {
+    /// Completed tasks are kept in the history before the state is reset
+    pub fn clear_todays_state(
+        &mut self,
+        file_path: &PathBuf,
+        history: &History,
+    ) -> Result<String, String> {
+        let day = ReportDay::from_daily_state(self);
+
+        if !day.tasks.is_empty() {
+            history.archive(&day)?;
+        }
+
         self.completed_tasks = Mutex::new(vec![]);
         self.current_task = None;
         self.start_time = Local::now();
         self.end_time = None;
+        self.plan = Plan::default();
 
         let result = remove_file(file_path);
 
@@ -81,6 +115,7 @@ impl DailyState {
             completed_tasks: Default::default(),
             start_time: Local::now(),
             end_time: None,
+            plan: Plan::default(),
         };
 
         let _ = new_state.save(file_path);
@@ -129,127 +164,11 @@ impl DailyState {
         }
     }
 
-    pub fn save_state_as_xlsx(&mut self, model: &Session) -> Result<String, ()> {
-        let mut workbook = Workbook::new();
-        let worksheet = workbook.add_worksheet();
-
-        let date_format = Format::new()
-            .set_num_format("hh:mm")
-            .set_text_wrap()
-            .set_background_color("#EEEEEE")
-            .set_font_name("Nunito")
-            .set_font_size(10)
-            .set_bold();
-
-        let date = self.start_time.format("%d.%m.%Y").to_string();
-
-        let _ = worksheet.set_name(&date);
-
-        let _ = worksheet.set_column_width(0, 15);
-        let _ = worksheet.set_column_width(1, 25);
-        let _ = worksheet.set_column_width(2, 40);
-
-        let _ = worksheet.set_name(&date);
-
-        let _ = worksheet.write_with_format(0, 0, &date, &date_format);
-
-        let completed_tasks = self.completed_tasks.lock().unwrap();
-
-        for (idx, task) in completed_tasks.to_vec().iter().enumerate() {
-            let date_format = Format::new()
-                .set_num_format("hh:mm")
-                .set_text_wrap()
-                .set_background_color("#EEEEEE")
-                .set_font_name("Nunito")
-                .set_font_size(10);
-
-            let start_time_xlsx = ExcelDateTime::from_hms(
-                task.dt_start.hour().try_into().unwrap(),
-                task.dt_start.minute().try_into().unwrap(),
-                task.dt_start.second(),
-            )
-            .unwrap();
-
-            let end_time_xlsx = ExcelDateTime::from_hms(
-                task.dt_end.hour().try_into().unwrap(),
-                task.dt_end.minute().try_into().unwrap(),
-                task.dt_end.second(),
-            )
-            .unwrap();
-
-            let row_idx: u32 = idx.try_into().unwrap();
-
-            let _ = worksheet.set_row_height(row_idx, 50);
-
-            let time_difference_formula = Formula::new(format!("=E{0}-D{0}", row_idx + 1));
-            let hours_total_formula = Formula::new(format!(
-                "=ROUND(HOUR(F{0})+MINUTE(F{0})/60+SECOND(F{0})/3600, 2)",
-                row_idx + 1
-            ));
-
-            let task_name_format = Format::new()
-                .set_text_wrap()
-                .set_background_color("#EEEEEE")
-                .set_font_name("Nunito")
-                .set_font_size(10);
-            let time_difference_format = Format::new()
-                .set_text_wrap()
-                .set_background_color("#EEEEEE")
-                .set_font_name("Nunito")
-                .set_font_size(10);
-            let hours_total_format = Format::new()
-                .set_text_wrap()
-                .set_background_color("#EEEEEE")
-                .set_font_name("Nunito")
-                .set_bold()
-                .set_font_size(10);
-
-            let x = vec![task.name.to_owned()];
-            let allocator = model.allocator();
-            let data = ([x.len()], x.into_boxed_slice());
-            let input = inputs![Value::from_string_array(allocator, data)?].unwrap();
-
-            let prediction_model_outputs = model.run(input).unwrap();
-
-            let (_length, task_tags) = &prediction_model_outputs[0]
-                .try_extract_raw_string_tensor()
-                .unwrap();
-
-            let _ = worksheet.write_with_format(row_idx, 1, &task_tags[0], &task_name_format);
-            let _ =
-                worksheet.write_with_format(row_idx, 2, task.name.to_owned(), &task_name_format);
-            let _ = worksheet.write_with_format(row_idx, 3, start_time_xlsx, &date_format);
-            let _ = worksheet.write_with_format(row_idx, 4, end_time_xlsx, &date_format);
-            let _ = worksheet.write_formula_with_format(
-                row_idx,
-                5,
-                time_difference_formula,
-                &time_difference_format,
-            );
-            let _ = worksheet.write_formula_with_format(
-                row_idx,
-                6,
-                hours_total_formula,
-                &hours_total_format,
-            );
-        }
-
-        let current_exe_path = env::current_exe().unwrap();
-        let current_dir_path = current_exe_path.parent().unwrap();
-        let current_dir_path_string = current_dir_path.to_str().unwrap();
-        let file_path = format!("{}/{}.xlsx", current_dir_path_string, date);
-
-        let save_result = workbook.save(&file_path);
-
-        match save_result {
-            Ok(_) => Ok(file_path.to_string()),
-            Err(_) => Err(()),
-        }
-    }
-
     pub fn complete_current_task(
         &mut self,
         task_completion_message: Option<String>,
+        dt_end: DateTime<Local>,
+        issue_key_matcher: &IssueKeyMatcher,
     ) -> Result<(), String> {
         let task_to_complite_option = self.current_task.take();
 
@@ -258,7 +177,7 @@ impl DailyState {
         }
 
         let task_to_complite = task_to_complite_option.unwrap();
-        let mut complited_task = task_to_complite.complete_task(task_completion_message);
+        let mut complited_task = task_to_complite.complete_task(task_completion_message, dt_end);
 
         let tasks = self.completed_tasks.lock();
 
@@ -266,6 +185,15 @@ impl DailyState {
             Ok(mut tasks) => {
                 let mut new_tasks_state = tasks.to_vec();
 
+                complited_task.issue = issue_key_matcher
+                    .find_issue_key(&complited_task.name)
+                    .or_else(|| {
+                        complited_task
+                            .end_comment
+                            .as_ref()
+                            .and_then(|comment| issue_key_matcher.find_issue_key(comment))
+                    });
+
                 if let Some(message) = &complited_task.end_comment {
                     complited_task.name =
                         [complited_task.name.to_owned(), message.to_owned()].join(". ");
@@ -280,4 +208,58 @@ impl DailyState {
             Err(err) => Err(err.to_string()),
         }
     }
+
+    /// Checks that a task can start at `dt_start` without changing anything
+    pub fn check_task_start(&self, dt_start: DateTime<Local>) -> Result<(), String> {
+        if dt_start > Local::now() {
+            return Err("Task can not start in the future".to_owned());
+        }
+
+        if dt_start < self.start_time {
+            return Err(format!(
+                "Task can not start before the day started at {}",
+                self.start_time.format("%d/%m/%Y %H:%M")
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Makes room for a task starting at `dt_start` by trimming the last completed task
+    pub fn fit_task_start(&mut self, dt_start: DateTime<Local>) -> Result<(), String> {
+        self.check_task_start(dt_start)?;
+
+        let tasks = self.completed_tasks.lock();
+
+        match tasks {
+            Ok(mut tasks) => {
+                if let Some(last_task) = tasks.last_mut() {
+                    if last_task.dt_start >= dt_start {
+                        return Err("Start time overlaps previous task".to_owned());
+                    }
+
+                    if last_task.dt_end > dt_start {
+                        last_task.dt_end = dt_start;
+                    }
+                }
+            }
+            Err(err) => return Err(err.to_string()),
+        }
+
+        Ok(())
+    }
+
+    pub fn set_current_task_start(&mut self, dt_start: DateTime<Local>) -> Result<(), String> {
+        if self.current_task.is_none() {
+            return Err("There is no running task".to_owned());
+        }
+
+        self.fit_task_start(dt_start)?;
+
+        if let Some(task) = &mut self.current_task {
+            task.dt_start = dt_start;
+        }
+
+        Ok(())
+    }
 }
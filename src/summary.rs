@@ -0,0 +1,175 @@
+use chrono::{prelude::*, Duration};
+use itertools::Itertools;
+
+use crate::report::{ReportDay, NO_TAG};
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum SummaryFormat {
+    Markdown,
+    PlainText,
+}
+
+pub struct SummaryItem {
+    pub name: String,
+    pub comments: Vec<String>,
+    pub duration: Duration,
+}
+
+/// Tasks sharing the same tag and project
+pub struct SummaryGroup {
+    pub title: String,
+    pub items: Vec<SummaryItem>,
+}
+
+/// Standup summary of a single day, repeated tasks are merged by name
+pub struct Summary {
+    pub date: NaiveDate,
+    pub groups: Vec<SummaryGroup>,
+}
+
+impl SummaryGroup {
+    pub fn duration(&self) -> Duration {
+        self.items
+            .iter()
+            .fold(Duration::zero(), |total, item| total + item.duration)
+    }
+}
+
+impl Summary {
+    pub fn from_day(day: &ReportDay) -> Summary {
+        let groups = day
+            .tasks
+            .iter()
+            .into_group_map_by(|task| (task.tag.to_owned(), task.project.to_owned()))
+            .into_iter()
+            .map(|((tag, project), tasks)| {
+                let tag = tag.unwrap_or(NO_TAG.to_owned());
+                let title = match project {
+                    Some(project) => format!("{} / {}", tag, project),
+                    None => tag,
+                };
+
+                let mut items: Vec<SummaryItem> = vec![];
+
+                for task in tasks {
+                    let name = task.name_without_comment();
+
+                    let item = match items.iter_mut().find(|item| item.name == name) {
+                        Some(item) => item,
+                        None => {
+                            items.push(SummaryItem {
+                                name,
+                                comments: vec![],
+                                duration: Duration::zero(),
+                            });
+                            items.last_mut().unwrap()
+                        }
+                    };
+
+                    item.duration = item.duration + task.duration();
+
+                    if let Some(comment) = &task.end_comment {
+                        if !item.comments.contains(comment) {
+                            item.comments.push(comment.to_owned());
+                        }
+                    }
+                }
+
+                SummaryGroup { title, items }
+            })
+            .sorted_by(|a, b| b.duration().cmp(&a.duration()).then(a.title.cmp(&b.title)))
+            .collect();
+
+        Summary {
+            date: day.date,
+            groups,
+        }
+    }
+
+    pub fn duration(&self) -> Duration {
+        self.groups
+            .iter()
+            .fold(Duration::zero(), |total, group| total + group.duration())
+    }
+
+    pub fn render(&self, format: SummaryFormat) -> String {
+        match format {
+            SummaryFormat::Markdown => self.to_markdown(),
+            SummaryFormat::PlainText => self.to_plain_text(),
+        }
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let mut lines = vec![format!(
+            "## {} ({})",
+            self.date.format("%d.%m.%Y"),
+            format_duration(self.duration())
+        )];
+
+        for group in &self.groups {
+            lines.push(String::new());
+            lines.push(format!(
+                "### {} ({})",
+                group.title,
+                format_duration(group.duration())
+            ));
+
+            for item in &group.items {
+                lines.push(format!(
+                    "- {} — {}{}",
+                    item.name,
+                    format_duration(item.duration),
+                    format_comments(&item.comments)
+                ));
+            }
+        }
+
+        lines.join("\n") + "\n"
+    }
+
+    pub fn to_plain_text(&self) -> String {
+        let mut lines = vec![format!(
+            "{} ({})",
+            self.date.format("%d.%m.%Y"),
+            format_duration(self.duration())
+        )];
+
+        for group in &self.groups {
+            lines.push(String::new());
+            lines.push(format!(
+                "{} ({})",
+                group.title,
+                format_duration(group.duration())
+            ));
+
+            for item in &group.items {
+                lines.push(format!(
+                    "  * {} - {}{}",
+                    item.name,
+                    format_duration(item.duration),
+                    format_comments(&item.comments)
+                ));
+            }
+        }
+
+        lines.join("\n") + "\n"
+    }
+}
+
+fn format_comments(comments: &[String]) -> String {
+    if comments.is_empty() {
+        String::new()
+    } else {
+        format!(" ({})", comments.join("; "))
+    }
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let minutes = duration.num_minutes();
+
+    match (minutes / 60, minutes % 60) {
+        (0, minutes) => format!("{}m", minutes),
+        (hours, 0) => format!("{}h", hours),
+        (hours, minutes) => format!("{}h {}m", hours, minutes),
+    }
+}
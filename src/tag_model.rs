@@ -0,0 +1,314 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::prelude::*;
+use std::path::PathBuf;
+
+use itertools::Itertools;
+use ort::{inputs, GraphOptimizationLevel, Session, Value};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    config::TagModelConfig,
+    utils::{escape_csv_field, fnv1a_hash},
+};
+
+const EMBEDDED_MODEL: &[u8] = include_bytes!("./../model/tag_predict_model.onnx");
+// Same columns and separator as the model training data in model/best_model.ipynb
+const CORRECTIONS_HEADER: &str = "Text;Label";
+const CORRECTIONS_DELIMITER: char = ';';
+
+/// Runs the model once for all the names
+pub fn predict_tags(model: &Session, task_names: &[String]) -> Result<Vec<String>, String> {
+    let x = task_names.to_vec();
+    let allocator = model.allocator();
+    let data = ([x.len()], x.into_boxed_slice());
+
+    let input = inputs![Value::from_string_array(allocator, data)?]
+        .map_err(|_| "Could not prepare model input".to_owned())?;
+
+    let prediction_model_outputs = model
+        .run(input)
+        .map_err(|_| "Could not run tag prediction model".to_owned())?;
+
+    let (_length, task_tags) = prediction_model_outputs[0]
+        .try_extract_raw_string_tensor()
+        .map_err(|_| "Could not read predicted tags".to_owned())?;
+
+    if task_tags.len() != task_names.len() {
+        return Err("Model returned a wrong number of tags".to_owned());
+    }
+
+    Ok(task_tags)
+}
+
+/// Predicted tags by normalized task name, kept between days.
+/// The cache belongs to one model, identified by a hash of the model file, so it is dropped
+/// when another model is configured or the file is replaced at the same path
+#[derive(Default, Serialize, Deserialize)]
+struct TagCache {
+    model: String,
+    tags: HashMap<String, String>,
+}
+
+/// Tags from the config rules and the prediction model, either of them can be missing.
+/// Loading and prediction never fail, the errors are kept to be shown to the user
+#[derive(Default)]
+pub struct TagPredictor {
+    session: Option<Session>,
+    rules: Vec<(Regex, String)>,
+    cache: RefCell<TagCache>,
+    cache_file: Option<PathBuf>,
+    pub load_errors: Vec<String>,
+    runtime_errors: RefCell<Vec<String>>,
+    /// Suggestions for names being typed, kept in memory only so prefixes do not fill the cache
+    suggestions: RefCell<HashMap<String, Option<String>>>,
+}
+
+impl TagPredictor {
+    pub fn init(config: &TagModelConfig, cache_file: &PathBuf) -> TagPredictor {
+        let mut tag_predictor = TagPredictor::default();
+
+        for rule in &config.rules {
+            match Regex::new(&rule.pattern) {
+                Ok(regex) => tag_predictor.rules.push((regex, rule.tag.to_owned())),
+                Err(_) => tag_predictor
+                    .load_errors
+                    .push(format!("Invalid tag rule pattern: {}", rule.pattern)),
+            }
+        }
+
+        if !config.enabled {
+            return tag_predictor;
+        }
+
+        let model = match load_model(config.path.as_ref()) {
+            Ok((session, model)) => {
+                tag_predictor.session = Some(session);
+
+                model
+            }
+            Err(err) => {
+                tag_predictor.load_errors.push(err);
+
+                return tag_predictor;
+            }
+        };
+
+        let cache = match load_cache(cache_file) {
+            Ok(cache) if cache.model == model => cache,
+            Ok(_) => TagCache {
+                model,
+                tags: HashMap::new(),
+            },
+            Err(err) => {
+                tag_predictor.load_errors.push(err);
+
+                TagCache {
+                    model,
+                    tags: HashMap::new(),
+                }
+            }
+        };
+
+        tag_predictor.cache = RefCell::new(cache);
+        tag_predictor.cache_file = Some(cache_file.to_owned());
+
+        tag_predictor
+    }
+
+    /// Errors of the predictions made since the last call, the tags of those tasks stay empty
+    pub fn take_runtime_errors(&self) -> Vec<String> {
+        self.runtime_errors.take()
+    }
+
+    pub fn predict(&self, task_name: &str) -> Option<String> {
+        self.predict_many(&[task_name.to_owned()])
+            .into_iter()
+            .next()
+            .flatten()
+    }
+
+    /// Suggestion for a name that is still being typed, it is not stored in the tag cache file.
+    /// The model runs once per normalized name, a name typed again is answered from memory
+    pub fn suggest(&self, task_name: &str) -> Option<String> {
+        if let Some((_, tag)) = self
+            .rules
+            .iter()
+            .find(|(regex, _)| regex.is_match(task_name))
+        {
+            return Some(tag.to_owned());
+        }
+
+        let normalized_name = normalize_task_name(task_name);
+
+        if let Some(tag) = self.cache.borrow().tags.get(&normalized_name) {
+            return Some(tag.to_owned());
+        }
+
+        if let Some(tag) = self.suggestions.borrow().get(&normalized_name) {
+            return tag.to_owned();
+        }
+
+        let session = self.session.as_ref()?;
+
+        let tag = match predict_tags(session, &[normalized_name.to_owned()]) {
+            Ok(tags) => tags.into_iter().next(),
+            Err(err) => {
+                self.runtime_errors.borrow_mut().push(err);
+
+                return None;
+            }
+        };
+
+        self.suggestions
+            .borrow_mut()
+            .insert(normalized_name, tag.to_owned());
+
+        tag
+    }
+
+    /// Rules are tried first in the configured order, the names left are looked up
+    /// in the cache and the ones not found there go to the model in one run
+    pub fn predict_many(&self, task_names: &[String]) -> Vec<Option<String>> {
+        let rule_tags: Vec<Option<String>> = task_names
+            .iter()
+            .map(|task_name| {
+                self.rules
+                    .iter()
+                    .find(|(regex, _)| regex.is_match(task_name))
+                    .map(|(_, tag)| tag.to_owned())
+            })
+            .collect();
+
+        let Some(session) = &self.session else {
+            return rule_tags;
+        };
+
+        let uncached_names: Vec<String> = task_names
+            .iter()
+            .zip(&rule_tags)
+            .filter(|(_, rule_tag)| rule_tag.is_none())
+            .map(|(task_name, _)| normalize_task_name(task_name))
+            .unique()
+            .filter(|name| !self.cache.borrow().tags.contains_key(name))
+            .collect();
+
+        if !uncached_names.is_empty() {
+            match predict_tags(session, &uncached_names) {
+                Ok(tags) => {
+                    self.cache
+                        .borrow_mut()
+                        .tags
+                        .extend(uncached_names.into_iter().zip(tags));
+
+                    if let Err(err) = self.save_cache() {
+                        self.runtime_errors.borrow_mut().push(err);
+                    }
+                }
+                Err(err) => self.runtime_errors.borrow_mut().push(err),
+            }
+        }
+
+        let cache = self.cache.borrow();
+
+        task_names
+            .iter()
+            .zip(rule_tags)
+            .map(|(task_name, rule_tag)| {
+                rule_tag.or_else(|| cache.tags.get(&normalize_task_name(task_name)).cloned())
+            })
+            .collect()
+    }
+
+    fn save_cache(&self) -> Result<(), String> {
+        let Some(cache_file) = &self.cache_file else {
+            return Ok(());
+        };
+
+        let json = serde_json::to_string(&*self.cache.borrow())
+            .map_err(|_| "Could not serialize tag cache".to_owned())?;
+
+        File::create(cache_file)
+            .and_then(|mut file| file.write_all(json.as_bytes()))
+            .map_err(|_| "Could not write tag cache file".to_owned())
+    }
+}
+
+/// Names differing only in case and spaces get the same tag
+fn normalize_task_name(task_name: &str) -> String {
+    task_name.split_whitespace().join(" ").to_lowercase()
+}
+
+fn load_cache(cache_file: &PathBuf) -> Result<TagCache, String> {
+    if !cache_file.exists() {
+        return Ok(TagCache::default());
+    }
+
+    let mut string = String::new();
+
+    let read_result = File::open(cache_file).and_then(|mut file| file.read_to_string(&mut string));
+
+    if read_result.is_err() {
+        return Err("Could not read tag cache file".to_owned());
+    }
+
+    serde_json::from_str(&string).map_err(|_| "Tag cache file is not valid JSON".to_owned())
+}
+
+/// The model embedded into the binary is used when no path is configured.
+/// Returns the session and a hash of the model file that identifies it in the tag cache
+pub fn load_model(path: Option<&PathBuf>) -> Result<(Session, String), String> {
+    let (model_bytes, error) = match path {
+        Some(path) => {
+            let error = format!(
+                "Could not load tag prediction model from {}",
+                path.to_string_lossy()
+            );
+
+            (std::fs::read(path).map_err(|_| error.to_owned())?, error)
+        }
+        None => (
+            EMBEDDED_MODEL.to_vec(),
+            "Could not load tag prediction model".to_owned(),
+        ),
+    };
+
+    let session = Session::builder()
+        .and_then(|builder| builder.with_optimization_level(GraphOptimizationLevel::Level3))
+        .and_then(|builder| builder.commit_from_memory(&model_bytes))
+        .map_err(|_| error)?;
+
+    Ok((session, format!("{:016x}", fnv1a_hash(&model_bytes))))
+}
+
+/// Appends `(task name, tag)` pairs, so the file can be added to the training data as is
+pub fn save_tag_corrections(
+    file_path: &PathBuf,
+    corrections: &[(String, String)],
+) -> Result<(), String> {
+    let mut lines = vec![];
+
+    if !file_path.exists() {
+        lines.push(CORRECTIONS_HEADER.to_owned());
+    }
+
+    lines.extend(corrections.iter().map(|(task_name, tag)| {
+        format!(
+            "{}{}{}",
+            escape_csv_field(task_name, CORRECTIONS_DELIMITER),
+            CORRECTIONS_DELIMITER,
+            escape_csv_field(tag, CORRECTIONS_DELIMITER)
+        )
+    }));
+
+    let content: String = lines.iter().map(|line| format!("{}\n", line)).collect();
+
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(file_path)
+        .and_then(|mut file| file.write_all(content.as_bytes()))
+        .map_err(|_| "Could not write tag corrections file".to_owned())
+}
@@ -1,10 +1,20 @@
-use chrono::prelude::*;
+use chrono::{prelude::*, Duration};
 use serde::{Deserialize, Serialize};
 
+use crate::utils::fnv1a_hash;
+
 #[derive(Clone, Serialize, Deserialize)]
 pub struct NotCompletedTask {
     pub name: String,
     pub dt_start: DateTime<Local>,
+    #[serde(default)]
+    pub tag: Option<String>,
+    #[serde(default)]
+    pub project: Option<String>,
+    #[serde(default)]
+    pub planned_minutes: Option<u32>,
+    #[serde(default)]
+    pub plan_item: Option<usize>,
 }
 
 #[derive(Clone, Serialize, Deserialize)]
@@ -13,6 +23,14 @@ pub struct CompletedTask {
     pub dt_start: DateTime<Local>,
     pub dt_end: DateTime<Local>,
     pub end_comment: Option<String>,
+    #[serde(default)]
+    pub issue: Option<String>,
+    #[serde(default)]
+    pub tag: Option<String>,
+    #[serde(default)]
+    pub project: Option<String>,
+    #[serde(default)]
+    pub plan_item: Option<usize>,
 }
 
 impl NotCompletedTask {
@@ -20,15 +38,59 @@ impl NotCompletedTask {
         NotCompletedTask {
             name,
             dt_start: Local::now(),
+            tag: None,
+            project: None,
+            planned_minutes: None,
+            plan_item: None,
         }
     }
 
-    pub fn complete_task(&self, end_comment: Option<String>) -> CompletedTask {
+    pub fn complete_task(
+        &self,
+        end_comment: Option<String>,
+        dt_end: DateTime<Local>,
+    ) -> CompletedTask {
         CompletedTask {
             name: self.name.to_string(),
             dt_start: self.dt_start.to_owned(),
-            dt_end: Local::now(),
+            dt_end,
             end_comment,
+            issue: None,
+            tag: self.tag.to_owned(),
+            project: self.project.to_owned(),
+            plan_item: self.plan_item,
         }
     }
 }
+
+impl CompletedTask {
+    /// Stable identifier built from the start, the end and the name. The start alone is not
+    /// enough: imported entries may start in the same second as tracked ones, and tools with
+    /// minute precision give several entries the same start. The end comment is left out, so a
+    /// snapshot of the running task has the same id as the task later completed with a comment,
+    /// but only when both end at the same time, as the tag review makes them do
+    pub fn id(&self) -> String {
+        format!(
+            "{}-{}-{:016x}",
+            self.dt_start.timestamp(),
+            self.dt_end.timestamp(),
+            fnv1a_hash(self.name_without_comment().as_bytes())
+        )
+    }
+
+    /// Task name without the end comment that is appended to it on completion
+    pub fn name_without_comment(&self) -> String {
+        match &self.end_comment {
+            Some(comment) => self
+                .name
+                .strip_suffix(&format!(". {}", comment))
+                .unwrap_or(&self.name)
+                .to_owned(),
+            None => self.name.to_owned(),
+        }
+    }
+
+    pub fn duration(&self) -> Duration {
+        self.dt_end - self.dt_start
+    }
+}
@@ -0,0 +1,117 @@
+use std::fs::File;
+use std::io::prelude::*;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::task::NotCompletedTask;
+
+pub const MAX_FAVOURITES: usize = 9;
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct TaskTemplate {
+    pub name: String,
+    #[serde(default)]
+    pub tag: Option<String>,
+    #[serde(default)]
+    pub project: Option<String>,
+    #[serde(default)]
+    pub planned_minutes: Option<u32>,
+    #[serde(default)]
+    pub favourite: bool,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+pub struct Templates {
+    pub templates: Vec<TaskTemplate>,
+}
+
+impl TaskTemplate {
+    pub fn start_task(&self) -> NotCompletedTask {
+        let mut task = NotCompletedTask::start(self.name.to_owned());
+
+        task.tag = self.tag.to_owned();
+        task.project = self.project.to_owned();
+        task.planned_minutes = self.planned_minutes;
+
+        task
+    }
+}
+
+impl Templates {
+    pub fn init(file_path: &PathBuf) -> Result<Templates, String> {
+        let result_with_opened_file = File::open(file_path);
+
+        match result_with_opened_file {
+            Ok(mut file) => {
+                let mut string = String::new();
+                let read_result = file.read_to_string(&mut string);
+
+                match read_result {
+                    Ok(_) => match serde_json::from_str(&string) {
+                        Ok(templates) => Ok(templates),
+                        Err(_) => Err("Templates file is not valid JSON".to_owned()),
+                    },
+                    Err(_) => Err("Could not read templates file".to_owned()),
+                }
+            }
+            Err(_) => {
+                let templates = Templates::with_examples();
+
+                let _ = templates.save(file_path);
+
+                Ok(templates)
+            }
+        }
+    }
+
+    pub fn save(&self, file_path: &PathBuf) -> Result<String, String> {
+        let result_with_string = serde_json::to_string_pretty(self);
+
+        match result_with_string {
+            Ok(string) => match File::create(file_path) {
+                Ok(mut file) => match file.write_all(string.as_bytes()) {
+                    Ok(_) => Ok("Templates saved".to_owned()),
+                    Err(_) => Err("Could not write templates to file".to_owned()),
+                },
+                Err(_) => Err("Could not create templates file".to_owned()),
+            },
+            Err(_) => Err("Could not save templates as JSON".to_owned()),
+        }
+    }
+
+    /// Favourites in file order, the first one is bound to key 1
+    pub fn favourites(&self) -> Vec<&TaskTemplate> {
+        self.templates
+            .iter()
+            .filter(|template| template.favourite)
+            .take(MAX_FAVOURITES)
+            .collect()
+    }
+
+    pub fn get_favourite(&self, number: usize) -> Option<&TaskTemplate> {
+        if number == 0 {
+            return None;
+        }
+
+        self.favourites().get(number - 1).copied()
+    }
+
+    fn with_examples() -> Templates {
+        let example = |name: &str, planned_minutes: u32| TaskTemplate {
+            name: name.to_owned(),
+            tag: None,
+            project: None,
+            planned_minutes: Some(planned_minutes),
+            favourite: true,
+        };
+
+        Templates {
+            templates: vec![
+                example("Daily standup", 15),
+                example("Code review", 60),
+                example("Email triage", 30),
+            ],
+        }
+    }
+}
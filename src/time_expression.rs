@@ -0,0 +1,98 @@
+use chrono::{prelude::*, Duration};
+
+/// Parses a point in the past like `now`, `-20m`, `1h30m ago`, `at 9:30` or `since 10`
+///
+/// Clock times refer to today. A clock time later than `now` refers to yesterday only when
+/// the day started at `day_start` before midnight, otherwise it is rejected
+pub fn parse_time_expression(
+    input: &str,
+    now: DateTime<Local>,
+    day_start: DateTime<Local>,
+) -> Result<DateTime<Local>, String> {
+    let error = || format!("Could not parse time: {}", input);
+
+    let expression = input.trim().to_lowercase();
+
+    if expression == "now" {
+        return Ok(now);
+    }
+
+    if let Some(duration) = expression.strip_prefix('-') {
+        return Ok(now - Duration::minutes(parse_duration_minutes(duration)?.into()));
+    }
+
+    if let Some(duration) = expression.strip_suffix("ago") {
+        return Ok(now - Duration::minutes(parse_duration_minutes(duration)?.into()));
+    }
+
+    let clock = ["at ", "since ", "from "]
+        .iter()
+        .find_map(|prefix| expression.strip_prefix(prefix))
+        .unwrap_or(&expression)
+        .trim();
+
+    let (hours, minutes) = match clock.split_once([':', '.']) {
+        Some((hours, minutes)) => (hours, minutes),
+        None => (clock, "0"),
+    };
+
+    let hours: u32 = hours.parse().map_err(|_| error())?;
+    let minutes: u32 = minutes.parse().map_err(|_| error())?;
+
+    let time = NaiveTime::from_hms_opt(hours, minutes, 0).ok_or_else(error)?;
+
+    let mut date = now.date_naive();
+
+    if date.and_time(time) > now.naive_local() {
+        if day_start.date_naive() >= date {
+            return Err(format!("{} is later than now", clock));
+        }
+
+        date = date.pred_opt().ok_or_else(error)?;
+    }
+
+    Local
+        .from_local_datetime(&date.and_time(time))
+        .earliest()
+        .ok_or_else(error)
+}
+
+/// Parses `90`, `1h30m` or `45m`, a bare number is read as minutes
+pub fn parse_duration_minutes(input: &str) -> Result<u32, String> {
+    let error = || format!("Could not parse duration: {}", input);
+
+    let input = input.trim();
+
+    if let Ok(minutes) = input.parse::<u32>() {
+        return Ok(minutes);
+    }
+
+    let mut total_minutes: u32 = 0;
+    let mut number = String::new();
+
+    for char in input.chars().filter(|char| !char.is_whitespace()) {
+        if char.is_ascii_digit() {
+            number.push(char);
+            continue;
+        }
+
+        let value: u32 = number.parse().map_err(|_| error())?;
+        number.clear();
+
+        let minutes = match char {
+            'h' | 'ч' => value.checked_mul(60),
+            'm' | 'м' => Some(value),
+            _ => None,
+        };
+
+        total_minutes = minutes
+            .and_then(|minutes| total_minutes.checked_add(minutes))
+            .ok_or_else(error)?;
+    }
+
+    if !number.is_empty() {
+        return Err(error());
+    }
+
+    Ok(total_minutes)
+}
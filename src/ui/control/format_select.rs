@@ -0,0 +1,129 @@
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Style, Stylize},
+    symbols,
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::theme::THEME;
+
+#[derive(Clone, PartialEq)]
+pub struct FormatSelect {
+    pub is_focused: bool,
+    pub formats: Vec<(String, bool)>,
+    pub cursor: usize,
+}
+
+impl FormatSelect {
+    pub fn init(available_formats: Vec<String>, selected_formats: &[String]) -> FormatSelect {
+        FormatSelect {
+            is_focused: false,
+            formats: available_formats
+                .into_iter()
+                .map(|format| {
+                    let is_selected = selected_formats.contains(&format);
+                    (format, is_selected)
+                })
+                .collect(),
+            cursor: 0,
+        }
+    }
+
+    pub fn toggle_focus(&mut self) {
+        self.is_focused = !self.is_focused
+    }
+
+    pub fn set_focus(&mut self) {
+        self.is_focused = true
+    }
+
+    pub fn unset_focus(&mut self) {
+        self.is_focused = false
+    }
+
+    /// Formats are numbered from 1 as they are shown on the screen
+    pub fn toggle_format(&mut self, number: usize) {
+        if number == 0 {
+            return;
+        }
+
+        if let Some((_, is_selected)) = self.formats.get_mut(number - 1) {
+            *is_selected = !*is_selected;
+        }
+    }
+
+    pub fn move_cursor_forwards(&mut self) {
+        if !self.formats.is_empty() {
+            self.cursor = (self.cursor + 1) % self.formats.len();
+        }
+    }
+
+    pub fn move_cursor_backwards(&mut self) {
+        if !self.formats.is_empty() {
+            self.cursor = (self.cursor + self.formats.len() - 1) % self.formats.len();
+        }
+    }
+
+    /// Reaches the formats past the ninth one, which have no number key
+    pub fn toggle_format_under_cursor(&mut self) {
+        self.toggle_format(self.cursor + 1);
+    }
+
+    pub fn selected_formats(&self) -> Vec<String> {
+        self.formats
+            .iter()
+            .filter(|(_, is_selected)| *is_selected)
+            .map(|(format, _)| format.to_owned())
+            .collect()
+    }
+
+    pub fn render(state: &FormatSelect, frame: &mut Frame, area: Rect) {
+        let mut paragraph_border_style = Style::new().blue();
+
+        if state.is_focused {
+            paragraph_border_style = paragraph_border_style.yellow();
+        }
+
+        let mut paragraph_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(paragraph_border_style)
+            .title("Export formats (number or Tab and Space to toggle)");
+
+        if state.is_focused {
+            paragraph_block = paragraph_block.border_set(symbols::border::THICK);
+        }
+
+        let spans: Vec<Span> = state
+            .formats
+            .iter()
+            .enumerate()
+            .flat_map(|(idx, (format, is_selected))| {
+                let checkbox = if *is_selected { "[x]" } else { "[ ]" };
+                let key = if idx < 9 {
+                    format!(" {} ", idx + 1)
+                } else {
+                    " · ".to_owned()
+                };
+                let label = format!(" {} {}   ", checkbox, format);
+
+                [
+                    Span::styled(key, THEME.key_binding.key),
+                    if state.is_focused && idx == state.cursor {
+                        Span::styled(label, Style::new().yellow().bold())
+                    } else {
+                        Span::raw(label)
+                    },
+                ]
+            })
+            .collect();
+
+        let paragraph = Paragraph::new(Line::from(spans))
+            .alignment(Alignment::Center)
+            .block(paragraph_block)
+            .wrap(Wrap { trim: true });
+
+        frame.render_widget(paragraph, area);
+    }
+}
@@ -1,3 +1,4 @@
+pub mod format_select;
 pub mod input;
 pub mod submit_btn;
 
@@ -5,12 +6,15 @@ use std::fmt::Display;
 
 use ratatui::{layout::Rect, Frame};
 
-pub use self::{input::Input, submit_btn::SubmitButton};
+pub use self::{format_select::FormatSelect, input::Input, submit_btn::SubmitButton};
 
 #[derive(Clone, PartialEq)]
 pub enum Control {
     EndCommentInput(Input),
     TaskNameInput(Input),
+    PlanItemInput(Input),
+    StartTimeInput(Input),
+    ExportFormats(FormatSelect),
     SubmitBtn(SubmitButton),
 }
 
@@ -19,6 +23,9 @@ impl Display for Control {
         match self {
             Control::TaskNameInput(_) => write!(f, "Task name"),
             Control::EndCommentInput(_) => write!(f, "Task end comment"),
+            Control::PlanItemInput(_) => write!(f, "Planned task"),
+            Control::StartTimeInput(_) => write!(f, "Task start time"),
+            Control::ExportFormats(_) => write!(f, "Export formats"),
             Control::SubmitBtn(_) => write!(f, "Submit btn"),
         }
     }
@@ -39,6 +46,20 @@ impl Control {
 
                 Input::render(title, state, placeholder, frame, area)
             }
+            Control::PlanItemInput(state) => {
+                let title = "New planned task (Enter to add)";
+                let placeholder = "Enter task name ~ estimate, e.g. Code review ~ 1h30m";
+
+                Input::render(title, state, placeholder, frame, area)
+            }
+            Control::StartTimeInput(state) => {
+                let title = "Started at (optional)";
+                let placeholder =
+                    "now, -20m, at 9:30 or since 10. Leave task name empty to move the running task";
+
+                Input::render(title, state, placeholder, frame, area)
+            }
+            Control::ExportFormats(state) => FormatSelect::render(state, frame, area),
             Control::SubmitBtn(state) => SubmitButton::render(state, frame, area),
         }
     }
@@ -47,6 +68,9 @@ impl Control {
         match self {
             Control::TaskNameInput(state) => state.clear_input(),
             Control::EndCommentInput(state) => state.clear_input(),
+            Control::PlanItemInput(state) => state.clear_input(),
+            Control::StartTimeInput(state) => state.clear_input(),
+            Control::ExportFormats(state) => state.unset_focus(),
             Control::SubmitBtn(_) => {}
         }
     }
@@ -55,6 +79,9 @@ impl Control {
         match self {
             Control::TaskNameInput(state) => state.toggle_focus(),
             Control::EndCommentInput(state) => state.toggle_focus(),
+            Control::PlanItemInput(state) => state.toggle_focus(),
+            Control::StartTimeInput(state) => state.toggle_focus(),
+            Control::ExportFormats(state) => state.toggle_focus(),
             Control::SubmitBtn(state) => state.toggle_focus(),
         }
     }
@@ -63,6 +90,9 @@ impl Control {
         match self {
             Control::TaskNameInput(state) => state.set_focus(),
             Control::EndCommentInput(state) => state.set_focus(),
+            Control::PlanItemInput(state) => state.set_focus(),
+            Control::StartTimeInput(state) => state.set_focus(),
+            Control::ExportFormats(state) => state.set_focus(),
             Control::SubmitBtn(state) => state.set_focus(),
         }
     }
@@ -71,6 +101,9 @@ impl Control {
         match self {
             Control::TaskNameInput(state) => state.unset_focus(),
             Control::EndCommentInput(state) => state.unset_focus(),
+            Control::PlanItemInput(state) => state.unset_focus(),
+            Control::StartTimeInput(state) => state.unset_focus(),
+            Control::ExportFormats(state) => state.unset_focus(),
             Control::SubmitBtn(state) => state.unset_focus(),
         }
     }
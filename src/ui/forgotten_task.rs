@@ -0,0 +1,169 @@
+use chrono::prelude::*;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Style, Stylize},
+    symbols,
+    text::{Line, Text},
+    widgets::{Block, Borders, Clear, Padding, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::utils::centered_rect;
+
+use super::Input;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum ForgottenTaskReason {
+    LongRunning,
+    Idle,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum ForgottenTaskAction {
+    End,
+    Split,
+    Keep,
+}
+
+pub struct ForgottenTaskPrompt {
+    pub reason: ForgottenTaskReason,
+    pub task_name: String,
+    pub task_start: DateTime<Local>,
+    pub selected_action: ForgottenTaskAction,
+    pub time_input: Input,
+}
+
+const ACTIONS: [ForgottenTaskAction; 3] = [
+    ForgottenTaskAction::End,
+    ForgottenTaskAction::Split,
+    ForgottenTaskAction::Keep,
+];
+
+impl ForgottenTaskAction {
+    fn description(&self) -> &'static str {
+        match self {
+            ForgottenTaskAction::End => "End the task at the chosen time",
+            ForgottenTaskAction::Split => {
+                "Split: end at the chosen time, note the idle gap and continue from now"
+            }
+            ForgottenTaskAction::Keep => "Keep the task running",
+        }
+    }
+}
+
+impl ForgottenTaskPrompt {
+    pub fn init(
+        reason: ForgottenTaskReason,
+        task_name: String,
+        task_start: DateTime<Local>,
+        suggested_end: DateTime<Local>,
+    ) -> ForgottenTaskPrompt {
+        let mut time_input = Input::init();
+
+        time_input.input = suggested_end.format("%H:%M").to_string();
+        time_input.set_focus();
+
+        ForgottenTaskPrompt {
+            reason,
+            task_name,
+            task_start,
+            selected_action: ForgottenTaskAction::End,
+            time_input,
+        }
+    }
+
+    pub fn select_next_action(&mut self) {
+        let idx = ACTIONS
+            .iter()
+            .position(|action| *action == self.selected_action)
+            .unwrap();
+
+        if let Some(action) = ACTIONS.get(idx + 1) {
+            self.selected_action = *action;
+        }
+    }
+
+    pub fn select_previous_action(&mut self) {
+        let idx = ACTIONS
+            .iter()
+            .position(|action| *action == self.selected_action)
+            .unwrap();
+
+        if idx > 0 {
+            self.selected_action = ACTIONS[idx - 1];
+        }
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let popup_area = centered_rect(area, 70, 60);
+
+        let block = Block::new()
+            .borders(Borders::ALL)
+            .border_set(symbols::border::THICK)
+            .border_style(Style::new().yellow())
+            .title("Forgotten task?")
+            .padding(Padding::new(2, 2, 1, 1));
+
+        let inner_area = block.inner(popup_area);
+
+        frame.render_widget(Clear, popup_area);
+        frame.render_widget(block, popup_area);
+
+        let layouts = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![
+                Constraint::Percentage(35),
+                Constraint::Percentage(35),
+                Constraint::Percentage(30),
+            ])
+            .split(inner_area);
+
+        let explanation = match self.reason {
+            ForgottenTaskReason::LongRunning => format!(
+                "\"{}\" has been running since {}",
+                self.task_name,
+                self.task_start.format("%d/%m %H:%M")
+            ),
+            ForgottenTaskReason::Idle => format!(
+                "\"{}\" was started at {} and kept running while you were away",
+                self.task_name,
+                self.task_start.format("%d/%m %H:%M")
+            ),
+        };
+
+        frame.render_widget(
+            Paragraph::new(explanation)
+                .alignment(Alignment::Center)
+                .wrap(Wrap { trim: true }),
+            layouts[0],
+        );
+
+        let lines: Vec<Line> = ACTIONS
+            .iter()
+            .map(|action| {
+                let line = Line::from(action.description());
+
+                if *action == self.selected_action {
+                    line.style(Style::default().yellow().bold())
+                } else {
+                    line
+                }
+            })
+            .collect();
+
+        frame.render_widget(
+            Paragraph::new(Text::from(lines))
+                .alignment(Alignment::Center)
+                .block(Block::new().title("↑/↓ select, Enter apply")),
+            layouts[1],
+        );
+
+        Input::render(
+            "Chosen time",
+            &self.time_input,
+            "at 12:30 or -2h",
+            frame,
+            layouts[2],
+        );
+    }
+}
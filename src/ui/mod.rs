@@ -1,5 +1,9 @@
 pub mod control;
+pub mod forgotten_task;
+pub mod summary_popup;
 pub mod tabs;
+pub mod tag_review;
+pub mod tag_suggestion;
 
 use std::{
     fmt::Display,
@@ -16,12 +20,18 @@ use ratatui::{
     Frame,
 };
 
-use crate::{theme::THEME, utils::centered_rect};
+use crate::{plan::PlanReportRow, templates::Templates, theme::THEME, utils::centered_rect};
 use tabs::Tab;
 
 pub use control::{Control, Input};
 
-use self::control::SubmitButton;
+use self::{
+    control::{FormatSelect, SubmitButton},
+    forgotten_task::ForgottenTaskPrompt,
+    summary_popup::SummaryPopup,
+    tag_review::TagReviewPopup,
+    tag_suggestion::TagSuggestion,
+};
 
 pub enum AppStage {
     Waiting,
@@ -46,19 +56,41 @@ pub struct AppUiState {
     pub control_focused: Option<Arc<Mutex<Control>>>,
     pub task_name_input: Arc<Mutex<Control>>,
     pub task_end_comment_input: Arc<Mutex<Control>>,
+    pub plan_item_input: Arc<Mutex<Control>>,
+    pub task_start_time_input: Arc<Mutex<Control>>,
+    pub export_formats_select: Arc<Mutex<Control>>,
     pub stage: AppStage,
+    pub selected_template: usize,
+    pub selected_plan_item: usize,
+    pub forgotten_task_prompt: Option<ForgottenTaskPrompt>,
+    pub summary_popup: Option<SummaryPopup>,
+    pub tag_review_popup: Option<TagReviewPopup>,
+    pub status_message: Option<String>,
+    pub tag_suggestion: TagSuggestion,
 }
 
 impl AppUiState {
-    pub fn init() -> AppUiState {
+    pub fn init(available_formats: Vec<String>, selected_formats: &[String]) -> AppUiState {
         AppUiState {
+            export_formats_select: Arc::new(Mutex::new(Control::ExportFormats(
+                FormatSelect::init(available_formats, selected_formats),
+            ))),
             task_end_comment_input: Arc::new(Mutex::new(Control::EndCommentInput(Input::init()))),
             task_name_input: Arc::new(Mutex::new(Control::TaskNameInput(Input::init()))),
+            plan_item_input: Arc::new(Mutex::new(Control::PlanItemInput(Input::init()))),
+            task_start_time_input: Arc::new(Mutex::new(Control::StartTimeInput(Input::init()))),
             submit_btn: Arc::new(Mutex::new(Control::SubmitBtn(SubmitButton::init()))),
             active_tab: 0_usize,
             tabs: Tab::as_string_vec(),
             control_focused: None,
             stage: AppStage::Waiting,
+            selected_template: 0_usize,
+            selected_plan_item: 0_usize,
+            forgotten_task_prompt: None,
+            summary_popup: None,
+            tag_review_popup: None,
+            status_message: None,
+            tag_suggestion: TagSuggestion::default(),
         }
     }
 
@@ -76,7 +108,10 @@ impl AppUiState {
 
             self.active_tab = new_tab_idx;
 
-            if tab == Tab::Start.to_string() {
+            if tab == Tab::Plan.to_string() {
+                self.control_focused = Some(Arc::clone(&self.plan_item_input));
+                self.plan_item_input.lock().unwrap().set_focus();
+            } else if tab == Tab::Start.to_string() {
                 self.control_focused = Some(Arc::clone(&self.task_name_input));
                 self.task_name_input.lock().unwrap().set_focus();
             } else if tab == Tab::End.to_string() || tab == Tab::Out.to_string() {
@@ -109,7 +144,10 @@ impl AppUiState {
 
             self.active_tab = new_tab_idx;
 
-            if tab == Tab::Start.to_string() {
+            if tab == Tab::Plan.to_string() {
+                self.control_focused = Some(Arc::clone(&self.plan_item_input));
+                self.plan_item_input.lock().unwrap().set_focus();
+            } else if tab == Tab::Start.to_string() {
                 self.control_focused = Some(Arc::clone(&self.task_name_input));
                 self.task_name_input.lock().unwrap().toggle_focus();
             } else if tab == Tab::End.to_string() {
@@ -139,6 +177,12 @@ impl AppUiState {
 
                     match &mut *input_focused {
                         Control::TaskNameInput(state) => {
+                            self.control_focused = Some(Arc::clone(&self.task_start_time_input));
+
+                            state.unset_focus();
+                            self.task_start_time_input.lock().unwrap().set_focus();
+                        }
+                        Control::StartTimeInput(state) => {
                             self.control_focused = Some(Arc::clone(&self.task_end_comment_input));
 
                             state.unset_focus();
@@ -156,10 +200,40 @@ impl AppUiState {
                             state.unset_focus();
                             self.task_name_input.lock().unwrap().set_focus();
                         }
+                        _ => {}
                     }
                 }
             }
-            Tab::End | Tab::Out => {
+            Tab::End => {
+                let input_focused_cell_option = self.control_focused.take();
+
+                if let Some(input_focused_cell) = input_focused_cell_option {
+                    let mut input_focused = input_focused_cell.lock().unwrap();
+
+                    match &mut *input_focused {
+                        Control::EndCommentInput(state) => {
+                            self.control_focused = Some(Arc::clone(&self.export_formats_select));
+
+                            state.unset_focus();
+                            self.export_formats_select.lock().unwrap().set_focus();
+                        }
+                        Control::ExportFormats(state) => {
+                            self.control_focused = Some(Arc::clone(&self.submit_btn));
+
+                            state.unset_focus();
+                            self.submit_btn.lock().unwrap().set_focus();
+                        }
+                        Control::SubmitBtn(state) => {
+                            self.control_focused = Some(Arc::clone(&self.task_end_comment_input));
+
+                            state.unset_focus();
+                            self.task_end_comment_input.lock().unwrap().set_focus();
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            Tab::Out => {
                 let input_focused_cell_option = self.control_focused.take();
 
                 if let Some(input_focused_cell) = input_focused_cell_option {
@@ -204,6 +278,12 @@ impl AppUiState {
                             self.submit_btn.lock().unwrap().set_focus();
                         }
                         Control::EndCommentInput(state) => {
+                            self.control_focused = Some(Arc::clone(&self.task_start_time_input));
+
+                            state.unset_focus();
+                            self.task_start_time_input.lock().unwrap().set_focus();
+                        }
+                        Control::StartTimeInput(state) => {
                             self.control_focused = Some(Arc::clone(&self.task_name_input));
 
                             state.unset_focus();
@@ -215,10 +295,40 @@ impl AppUiState {
                             state.unset_focus();
                             self.task_end_comment_input.lock().unwrap().set_focus();
                         }
+                        _ => {}
+                    }
+                }
+            }
+            Tab::End => {
+                let input_focused_cell_option = self.control_focused.take();
+
+                if let Some(input_focused_cell) = input_focused_cell_option {
+                    let mut input_focused = input_focused_cell.lock().unwrap();
+
+                    match &mut *input_focused {
+                        Control::EndCommentInput(state) => {
+                            self.control_focused = Some(Arc::clone(&self.submit_btn));
+
+                            state.unset_focus();
+                            self.submit_btn.lock().unwrap().set_focus();
+                        }
+                        Control::SubmitBtn(state) => {
+                            self.control_focused = Some(Arc::clone(&self.export_formats_select));
+
+                            state.unset_focus();
+                            self.export_formats_select.lock().unwrap().set_focus();
+                        }
+                        Control::ExportFormats(state) => {
+                            self.control_focused = Some(Arc::clone(&self.task_end_comment_input));
+
+                            state.unset_focus();
+                            self.task_end_comment_input.lock().unwrap().set_focus();
+                        }
+                        _ => {}
                     }
                 }
             }
-            Tab::End | Tab::Out => {
+            Tab::Out => {
                 let input_focused_cell_option = self.control_focused.take();
 
                 if let Some(input_focused_cell) = input_focused_cell_option {
@@ -248,7 +358,11 @@ impl AppUiState {
     pub fn clear_inputs_state(&mut self) {
         self.task_name_input.lock().unwrap().clear_input();
         self.task_end_comment_input.lock().unwrap().clear_input();
+        self.plan_item_input.lock().unwrap().clear_input();
+        self.task_start_time_input.lock().unwrap().clear_input();
+        self.export_formats_select.lock().unwrap().clear_input();
         self.submit_btn.lock().unwrap().unset_focus();
+        self.tag_suggestion = TagSuggestion::default();
     }
 
     pub fn get_active_tab(&self) -> Tab {
@@ -256,8 +370,12 @@ impl AppUiState {
 
         if current_active_idx == Tab::Home as usize {
             Tab::Home
+        } else if current_active_idx == Tab::Plan as usize {
+            Tab::Plan
         } else if current_active_idx == Tab::Start as usize {
             Tab::Start
+        } else if current_active_idx == Tab::Templates as usize {
+            Tab::Templates
         } else if current_active_idx == Tab::Out as usize {
             Tab::Out
         } else if current_active_idx == Tab::End as usize {
@@ -273,6 +391,30 @@ impl AppUiState {
         self.active_tab
     }
 
+    pub fn select_next_plan_item(&mut self, items_count: usize) {
+        if self.selected_plan_item + 1 < items_count {
+            self.selected_plan_item += 1;
+        }
+    }
+
+    pub fn select_previous_plan_item(&mut self) {
+        if self.selected_plan_item > 0 {
+            self.selected_plan_item -= 1;
+        }
+    }
+
+    pub fn select_next_template(&mut self, templates_count: usize) {
+        if self.selected_template + 1 < templates_count {
+            self.selected_template += 1;
+        }
+    }
+
+    pub fn select_previous_template(&mut self) {
+        if self.selected_template > 0 {
+            self.selected_template -= 1;
+        }
+    }
+
     pub fn render_home_tab(&self, frame: &mut Frame, area: Rect) {
         let mut lines: Vec<Line> = vec![];
 
@@ -302,6 +444,9 @@ impl AppUiState {
             ("→", "Right"),
             ("↑", "Up"),
             ("↓", "Down"),
+            ("1-9", "Favourite"),
+            ("s", "Standup summary"),
+            ("x", "Export now"),
             ("Esc", "Quit"),
         ];
         let spans = keys
@@ -331,31 +476,168 @@ impl AppUiState {
         frame.render_widget(text, area)
     }
 
+    pub fn render_plan_tab(&self, frame: &mut Frame, area: Rect, plan_rows: &[PlanReportRow]) {
+        let inner_area = centered_rect(area, 90, 90);
+
+        let area_vertical_layouts = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![Constraint::Percentage(75), Constraint::Percentage(25)])
+            .split(inner_area);
+
+        let mut lines: Vec<Line> = vec![];
+
+        if plan_rows.is_empty() {
+            lines.push(Line::from("Nothing planned yet. Add tasks below"));
+        }
+
+        for (idx, plan_row) in plan_rows.iter().enumerate() {
+            let estimate = match plan_row.estimate_minutes {
+                Some(estimate_minutes) => format!("{} min", estimate_minutes),
+                None => "-".to_owned(),
+            };
+
+            let mut line = Line::from(vec![
+                Span::raw(format!(" {}", plan_row.name)),
+                Span::styled(
+                    format!(
+                        "  estimate {} / actual {} min",
+                        estimate, plan_row.actual_minutes
+                    ),
+                    Style::default().dark_gray(),
+                ),
+            ]);
+
+            if !plan_row.is_planned {
+                line = line.style(Style::default().light_blue());
+            } else if idx == self.selected_plan_item {
+                line = line.style(Style::default().yellow().bold());
+            }
+
+            lines.push(line);
+        }
+
+        let text = Paragraph::new(Text::from(lines))
+            .wrap(Wrap { trim: true })
+            .block(
+                Block::new()
+                    .borders(Borders::ALL)
+                    .border_style(Style::new().blue())
+                    .title("Plan (↑/↓ select, Enter start, Del remove)")
+                    .padding(Padding::new(1, 1, 1, 1)),
+            );
+
+        frame.render_widget(text, area_vertical_layouts[0]);
+
+        self.plan_item_input
+            .lock()
+            .unwrap()
+            .render(frame, area_vertical_layouts[1]);
+    }
+
     pub fn render_start_tab(&self, frame: &mut Frame, area: Rect) {
         let inner_area = centered_rect(area, 90, 70);
 
         let area_vertical_layouts = Layout::default()
             .direction(Direction::Vertical)
             .constraints(vec![
-                Constraint::Percentage(37),
-                Constraint::Percentage(38),
-                Constraint::Percentage(25),
+                Constraint::Percentage(30),
+                Constraint::Percentage(20),
+                Constraint::Percentage(30),
+                Constraint::Percentage(20),
             ])
             .split(inner_area);
 
+        let task_name_layouts = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![Constraint::Min(3), Constraint::Length(1)])
+            .split(area_vertical_layouts[0]);
+
         self.task_name_input
             .lock()
             .unwrap()
-            .render(frame, area_vertical_layouts[0]);
+            .render(frame, task_name_layouts[0]);
 
-        self.task_end_comment_input
+        self.tag_suggestion.render(frame, task_name_layouts[1]);
+
+        self.task_start_time_input
             .lock()
             .unwrap()
             .render(frame, area_vertical_layouts[1]);
+
+        self.task_end_comment_input
+            .lock()
+            .unwrap()
+            .render(frame, area_vertical_layouts[2]);
         self.submit_btn
             .lock()
             .unwrap()
-            .render(frame, area_vertical_layouts[2])
+            .render(frame, area_vertical_layouts[3])
+    }
+
+    pub fn render_templates_tab(&self, frame: &mut Frame, area: Rect, templates: &Templates) {
+        let inner_area = centered_rect(area, 90, 80);
+
+        let favourites = templates.favourites();
+
+        let mut lines: Vec<Line> = vec![];
+
+        if templates.templates.is_empty() {
+            lines.push(Line::from("No templates yet. Add them to templates.json"));
+        }
+
+        for (idx, template) in templates.templates.iter().enumerate() {
+            let favourite_key = favourites
+                .iter()
+                .position(|favourite| std::ptr::eq(*favourite, template))
+                .map(|position| format!("[{}]", position + 1))
+                .unwrap_or_default();
+
+            let mut spans = vec![
+                Span::styled(format!(" {:>3} ", favourite_key), THEME.key_binding.key),
+                Span::raw(format!(" {}", template.name)),
+            ];
+
+            if let Some(tag) = &template.tag {
+                spans.push(Span::styled(
+                    format!("  #{}", tag),
+                    Style::default().light_blue(),
+                ));
+            }
+
+            if let Some(project) = &template.project {
+                spans.push(Span::styled(
+                    format!("  @{}", project),
+                    Style::default().light_blue(),
+                ));
+            }
+
+            if let Some(planned_minutes) = template.planned_minutes {
+                spans.push(Span::styled(
+                    format!("  ~{} min", planned_minutes),
+                    Style::default().dark_gray(),
+                ));
+            }
+
+            let mut line = Line::from(spans);
+
+            if idx == self.selected_template {
+                line = line.style(Style::default().yellow().bold());
+            }
+
+            lines.push(line);
+        }
+
+        let text = Paragraph::new(Text::from(lines))
+            .wrap(Wrap { trim: true })
+            .block(
+                Block::new()
+                    .borders(Borders::ALL)
+                    .border_style(Style::new().blue())
+                    .title("Templates (↑/↓ select, Enter start)")
+                    .padding(Padding::new(1, 1, 1, 1)),
+            );
+
+        frame.render_widget(text, inner_area)
     }
 
     pub fn render_out_tab(&self, frame: &mut Frame, area: Rect) {
@@ -392,15 +674,23 @@ impl AppUiState {
 
         let area_vertical_layouts = Layout::default()
             .direction(Direction::Vertical)
-            .constraints(vec![Constraint::Percentage(40), Constraint::Percentage(60)])
+            .constraints(vec![
+                Constraint::Percentage(35),
+                Constraint::Percentage(30),
+                Constraint::Percentage(35),
+            ])
             .split(inner_area);
 
-        let inner_bottom_area = centered_rect(area_vertical_layouts[1], 50, 50);
+        let inner_bottom_area = centered_rect(area_vertical_layouts[2], 50, 70);
 
         self.task_end_comment_input
             .lock()
             .unwrap()
             .render(frame, area_vertical_layouts[0]);
+        self.export_formats_select
+            .lock()
+            .unwrap()
+            .render(frame, area_vertical_layouts[1]);
         self.submit_btn
             .lock()
             .unwrap()
@@ -0,0 +1,127 @@
+use chrono::prelude::*;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Style, Stylize},
+    symbols,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Padding, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::{
+    summary::{Summary, SummaryFormat},
+    theme::THEME,
+    utils::centered_rect,
+};
+
+pub struct SummaryPopup {
+    pub dates: Vec<NaiveDate>,
+    pub selected_date: usize,
+    pub summary: Summary,
+    pub format: SummaryFormat,
+    pub scroll: u16,
+    pub message: Option<String>,
+}
+
+impl SummaryPopup {
+    pub fn init(dates: Vec<NaiveDate>, selected_date: usize, summary: Summary) -> SummaryPopup {
+        SummaryPopup {
+            dates,
+            selected_date,
+            summary,
+            format: SummaryFormat::Markdown,
+            scroll: 0,
+            message: None,
+        }
+    }
+
+    pub fn toggle_format(&mut self) {
+        self.format = match self.format {
+            SummaryFormat::Markdown => SummaryFormat::PlainText,
+            SummaryFormat::PlainText => SummaryFormat::Markdown,
+        }
+    }
+
+    pub fn scroll_down(&mut self) {
+        self.scroll = self.scroll.saturating_add(1)
+    }
+
+    pub fn scroll_up(&mut self) {
+        self.scroll = self.scroll.saturating_sub(1)
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let popup_area = centered_rect(area, 80, 80);
+
+        let format_name = match self.format {
+            SummaryFormat::Markdown => "Markdown",
+            SummaryFormat::PlainText => "Plain text",
+        };
+
+        let block = Block::new()
+            .borders(Borders::ALL)
+            .border_set(symbols::border::THICK)
+            .border_style(Style::new().yellow())
+            .title(format!(
+                "Standup summary: {} ({})",
+                self.summary.date.format("%d.%m.%Y"),
+                format_name
+            ))
+            .padding(Padding::new(2, 2, 1, 1));
+
+        let inner_area = block.inner(popup_area);
+
+        frame.render_widget(Clear, popup_area);
+        frame.render_widget(block, popup_area);
+
+        let layouts = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![
+                Constraint::Min(1),
+                Constraint::Length(1),
+                Constraint::Length(1),
+            ])
+            .split(inner_area);
+
+        let text = if self.summary.groups.is_empty() {
+            "No completed tasks for this day".to_owned()
+        } else {
+            self.summary.render(self.format)
+        };
+
+        frame.render_widget(
+            Paragraph::new(text)
+                .wrap(Wrap { trim: false })
+                .scroll((self.scroll, 0)),
+            layouts[0],
+        );
+
+        if let Some(message) = &self.message {
+            frame.render_widget(
+                Paragraph::new(message.to_owned())
+                    .alignment(Alignment::Center)
+                    .light_blue(),
+                layouts[1],
+            );
+        }
+
+        let keys = [
+            ("←/→", "Day"),
+            ("↑/↓", "Scroll"),
+            ("Tab", "Format"),
+            ("w", "Write file"),
+            ("Esc", "Close"),
+        ];
+        let spans: Vec<Span> = keys
+            .iter()
+            .flat_map(|(key, desc)| {
+                [
+                    Span::styled(format!(" {} ", key), THEME.key_binding.key),
+                    Span::styled(format!(" {} ", desc), THEME.key_binding.description),
+                ]
+            })
+            .collect();
+
+        frame.render_widget(Paragraph::new(Line::from(spans).centered()), layouts[2]);
+    }
+}
@@ -5,7 +5,9 @@ use ratatui::text::Line;
 #[derive(Eq, PartialEq)]
 pub enum Tab {
     Home,
+    Plan,
     Start,
+    Templates,
     Out,
     End,
     ClearState,
@@ -15,7 +17,9 @@ impl From<Tab> for Line<'_> {
     fn from(val: Tab) -> Self {
         match val {
             Tab::Home => Line::raw(Tab::Home.to_string()),
+            Tab::Plan => Line::raw(Tab::Plan.to_string()),
             Tab::Start => Line::raw(Tab::Start.to_string()),
+            Tab::Templates => Line::raw(Tab::Templates.to_string()),
             Tab::Out => Line::raw(Tab::Out.to_string()),
             Tab::End => Line::raw(Tab::Out.to_string()),
             Tab::ClearState => Line::raw(Tab::ClearState.to_string()),
@@ -27,7 +31,9 @@ impl Tab {
     pub fn as_string_vec() -> Vec<String> {
         vec![
             Tab::Home.to_string(),
+            Tab::Plan.to_string(),
             Tab::Start.to_string(),
+            Tab::Templates.to_string(),
             Tab::Out.to_string(),
             Tab::End.to_string(),
             Tab::ClearState.to_string(),
@@ -39,7 +45,9 @@ impl Display for Tab {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Tab::Home => write!(f, "Home"),
+            Tab::Plan => write!(f, "Plan"),
             Tab::Start => write!(f, "Start"),
+            Tab::Templates => write!(f, "Templates"),
             Tab::Out => write!(f, "Out"),
             Tab::End => write!(f, "End"),
             Tab::ClearState => write!(f, "Clear State"),
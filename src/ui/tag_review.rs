@@ -0,0 +1,200 @@
+use std::collections::HashMap;
+
+use chrono::prelude::*;
+
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Style, Stylize},
+    symbols,
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Clear, Padding, Paragraph, Row, Table, TableState},
+    Frame,
+};
+
+use crate::{report::NO_TAG, task::CompletedTask, theme::THEME, utils::centered_rect};
+
+pub struct TagReviewRow {
+    pub task_id: String,
+    pub task_name: String,
+    pub time: String,
+    /// Tag of the task or the predicted one
+    pub original_tag: Option<String>,
+    pub is_predicted: bool,
+    pub tag: Option<String>,
+}
+
+/// Tags of the day shown before End writes the report, so wrong predictions can be fixed.
+/// The end comment and formats from the End tab wait here until the review is confirmed
+pub struct TagReviewPopup {
+    pub rows: Vec<TagReviewRow>,
+    pub selected_row: usize,
+    pub end_comment: Option<String>,
+    pub formats: Vec<String>,
+    /// Time the running task was completed at in the review
+    pub ended_at: DateTime<Local>,
+}
+
+impl TagReviewRow {
+    /// Untagged tasks come here with the predicted tag already assigned
+    pub fn from_task(task: &CompletedTask, is_predicted: bool) -> TagReviewRow {
+        TagReviewRow {
+            task_id: task.id(),
+            task_name: task.name_without_comment(),
+            time: format!(
+                "{}-{}",
+                task.dt_start.format("%H:%M"),
+                task.dt_end.format("%H:%M")
+            ),
+            original_tag: task.tag.to_owned(),
+            is_predicted,
+            tag: task.tag.to_owned(),
+        }
+    }
+
+    pub fn is_overridden(&self) -> bool {
+        self.tag != self.original_tag
+    }
+}
+
+impl TagReviewPopup {
+    pub fn init(
+        rows: Vec<TagReviewRow>,
+        end_comment: Option<String>,
+        formats: Vec<String>,
+        ended_at: DateTime<Local>,
+    ) -> TagReviewPopup {
+        TagReviewPopup {
+            rows,
+            selected_row: 0,
+            end_comment,
+            formats,
+            ended_at,
+        }
+    }
+
+    pub fn select_next_row(&mut self) {
+        if self.selected_row + 1 < self.rows.len() {
+            self.selected_row += 1;
+        }
+    }
+
+    pub fn select_previous_row(&mut self) {
+        self.selected_row = self.selected_row.saturating_sub(1);
+    }
+
+    /// Goes through the known tags in a circle, `step` is 1 or -1
+    pub fn change_tag(&mut self, known_tags: &[String], step: isize) {
+        let Some(row) = self.rows.get_mut(self.selected_row) else {
+            return;
+        };
+
+        if known_tags.is_empty() {
+            return;
+        }
+
+        let tags_count = known_tags.len() as isize;
+
+        let next_idx = match row
+            .tag
+            .as_ref()
+            .and_then(|tag| known_tags.iter().position(|known| known == tag))
+        {
+            Some(idx) => (idx as isize + step).rem_euclid(tags_count),
+            None if step > 0 => 0,
+            None => tags_count - 1,
+        };
+
+        row.tag = known_tags.get(next_idx as usize).cloned();
+    }
+
+    pub fn reset_tag(&mut self) {
+        if let Some(row) = self.rows.get_mut(self.selected_row) {
+            row.tag = row.original_tag.to_owned();
+        }
+    }
+
+    /// Tags of all rows by task id as the review shows them, predicted ones included
+    pub fn reviewed_tags(&self) -> HashMap<String, String> {
+        self.rows
+            .iter()
+            .filter_map(|row| Some((row.task_id.to_owned(), row.tag.to_owned()?)))
+            .collect()
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let popup_area = centered_rect(area, 80, 80);
+
+        let block = Block::new()
+            .borders(Borders::ALL)
+            .border_set(symbols::border::THICK)
+            .border_style(Style::new().yellow())
+            .title("Review tags before the report")
+            .padding(Padding::new(2, 2, 1, 1));
+
+        let inner_area = block.inner(popup_area);
+
+        frame.render_widget(Clear, popup_area);
+        frame.render_widget(block, popup_area);
+
+        let layouts = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![Constraint::Min(1), Constraint::Length(1)])
+            .split(inner_area);
+
+        let rows: Vec<Row> = self
+            .rows
+            .iter()
+            .map(|row| {
+                let tag = row.tag.to_owned().unwrap_or(NO_TAG.to_owned());
+
+                let tag_cell = if row.is_overridden() {
+                    Cell::from(tag).style(Style::default().yellow().bold())
+                } else if row.is_predicted {
+                    Cell::from(format!("{} (predicted)", tag)).style(Style::default().light_blue())
+                } else {
+                    Cell::from(tag)
+                };
+
+                Row::new(vec![
+                    Cell::from(row.time.to_owned()),
+                    Cell::from(row.task_name.to_owned()),
+                    tag_cell,
+                ])
+            })
+            .collect();
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(11),
+                Constraint::Percentage(55),
+                Constraint::Percentage(45),
+            ],
+        )
+        .header(Row::new(vec!["Time", "Task", "Tag"]).bold())
+        .highlight_style(Style::default().reversed());
+
+        let mut table_state = TableState::default().with_selected(Some(self.selected_row));
+
+        frame.render_stateful_widget(table, layouts[0], &mut table_state);
+
+        let keys = [
+            ("↑/↓", "Task"),
+            ("←/→", "Tag"),
+            ("Backspace", "Reset"),
+            ("Enter", "End day"),
+            ("Esc", "Cancel"),
+        ];
+        let spans: Vec<Span> = keys
+            .iter()
+            .flat_map(|(key, desc)| {
+                [
+                    Span::styled(format!(" {} ", key), THEME.key_binding.key),
+                    Span::styled(format!(" {} ", desc), THEME.key_binding.description),
+                ]
+            })
+            .collect();
+
+        frame.render_widget(Paragraph::new(Line::from(spans).centered()), layouts[1]);
+    }
+}
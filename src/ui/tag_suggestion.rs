@@ -0,0 +1,105 @@
+use std::time::{Duration, Instant};
+
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Style, Stylize},
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+use crate::theme::THEME;
+
+/// The model runs only after the name has not changed for this long
+const SUGGESTION_DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// Tag predicted for the task name being typed on the Start tab.
+/// Tab accepts the suggestion, Shift+Tab goes through the known tags instead.
+/// An accepted suggestion belongs to the name it was made for and is dropped when the name
+/// changes, a tag chosen with Shift+Tab is kept
+#[derive(Default)]
+pub struct TagSuggestion {
+    pub suggested_tag: Option<String>,
+    pub chosen_tag: Option<String>,
+    is_chosen_manually: bool,
+    typed_name: String,
+    typed_at: Option<Instant>,
+}
+
+impl TagSuggestion {
+    /// Called on every tick with the current input, true when the name settled and needs a new suggestion
+    pub fn observe_input(&mut self, task_name: &str) -> bool {
+        if task_name != self.typed_name {
+            self.typed_name = task_name.to_owned();
+            self.typed_at = Some(Instant::now());
+
+            if !self.is_chosen_manually {
+                self.chosen_tag = None;
+            }
+
+            return false;
+        }
+
+        match self.typed_at {
+            Some(typed_at) if typed_at.elapsed() >= SUGGESTION_DEBOUNCE => {
+                self.typed_at = None;
+
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// The suggestion shown while the name is still changing was made for an older name
+    pub fn accept(&mut self) {
+        if self.suggested_tag.is_some() && self.typed_at.is_none() {
+            self.chosen_tag = self.suggested_tag.to_owned();
+            self.is_chosen_manually = false;
+        }
+    }
+
+    /// Next known tag after the chosen one, no tag after the last one
+    pub fn choose_next(&mut self, known_tags: &[String]) {
+        let next_idx = match &self.chosen_tag {
+            Some(chosen_tag) => known_tags
+                .iter()
+                .position(|tag| tag == chosen_tag)
+                .map(|idx| idx + 1)
+                .unwrap_or(0),
+            None => 0,
+        };
+
+        self.chosen_tag = known_tags.get(next_idx).cloned();
+        self.is_chosen_manually = self.chosen_tag.is_some();
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let mut spans = match (&self.chosen_tag, &self.suggested_tag) {
+            (Some(chosen_tag), _) => vec![
+                Span::raw("Tag: "),
+                Span::styled(chosen_tag.to_owned(), Style::default().yellow().bold()),
+            ],
+            (None, Some(suggested_tag)) => vec![
+                Span::raw("Suggested tag: "),
+                Span::styled(suggested_tag.to_owned(), Style::default().light_blue()),
+            ],
+            (None, None) => vec![Span::styled(
+                "No tag suggested",
+                Style::default().dark_gray(),
+            )],
+        };
+
+        spans.extend([
+            Span::raw("  "),
+            Span::styled(" Tab ", THEME.key_binding.key),
+            Span::styled(" Accept ", THEME.key_binding.description),
+            Span::styled(" Shift+Tab ", THEME.key_binding.key),
+            Span::styled(" Change ", THEME.key_binding.description),
+        ]);
+
+        frame.render_widget(
+            Paragraph::new(Line::from(spans)).alignment(Alignment::Center),
+            area,
+        );
+    }
+}
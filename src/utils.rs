@@ -1,4 +1,4 @@
-use std::sync::MutexGuard;
+use std::{env, path::PathBuf, sync::MutexGuard};
 
 use chrono::Local;
 use itertools::Itertools;
@@ -6,7 +6,7 @@ use ratatui::layout::{Constraint, Direction, Layout, Rect};
 
 use crate::{state::DailyState, task::CompletedTask, ui::Input};
 
-const MILLISECONDS_IN_HOUR: f64 = 3600000_f64;
+pub const MILLISECONDS_IN_HOUR: f64 = 3600000_f64;
 
 pub fn centered_rect(r: Rect, percent_x: u16, percent_y: u16) -> Rect {
     let popup_layout = Layout::default()
@@ -28,6 +28,69 @@ pub fn centered_rect(r: Rect, percent_x: u16, percent_y: u16) -> Rect {
         .split(popup_layout[1])[1]
 }
 
+/// FNV-1a, unlike the std hasher its output does not change between Rust releases
+pub fn fnv1a_hash(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x100000001b3)
+    })
+}
+
+pub fn get_executable_dir() -> PathBuf {
+    let current_exe_path = env::current_exe().unwrap();
+    let current_dir_path = current_exe_path.parent().unwrap();
+
+    current_dir_path.to_path_buf()
+}
+
+pub fn escape_csv_field(field: &str, delimiter: char) -> String {
+    let needs_quoting = field.contains(delimiter)
+        || field.contains('"')
+        || field.contains('\n')
+        || field.contains('\r');
+
+    if needs_quoting {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
+
+/// Splits CSV content into records, quoted fields may contain delimiters, quotes and line breaks
+pub fn parse_csv(content: &str, delimiter: char) -> Vec<Vec<String>> {
+    let mut records = vec![];
+    let mut record = vec![];
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = content.trim_start_matches('\u{feff}').chars().peekable();
+
+    while let Some(char) = chars.next() {
+        match char {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => in_quotes = !in_quotes,
+            char if char == delimiter && !in_quotes => record.push(std::mem::take(&mut field)),
+            '\r' if !in_quotes => {}
+            '\n' if !in_quotes => {
+                record.push(std::mem::take(&mut field));
+                records.push(std::mem::take(&mut record));
+            }
+            char => field.push(char),
+        }
+    }
+
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+
+    records
+        .into_iter()
+        .filter(|record| record.iter().any(|field| !field.is_empty()))
+        .collect()
+}
+
 pub fn get_complited_tasks_names(state: &DailyState) -> Vec<String> {
     let complited_tasks_guard = state.completed_tasks.lock();
     if let Ok(tasks) = complited_tasks_guard {
@@ -75,7 +138,7 @@ pub fn set_task_name_from_previous_tasks(
 
     let task_search_idx_result = completed_tasks
         .iter()
-        .find_position(|task| task.name == current_task_name.to_owned());
+        .find_position(|task| task.name == *current_task_name);
 
     match task_search_idx_result {
         Some((idx, _)) => {
@@ -95,3 +158,41 @@ pub fn set_task_name_from_previous_tasks(
         }
     }
 }
+
+/// Empty directory under the system temp dir, unique per test name and process
+#[cfg(test)]
+pub fn test_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("time-manager-{}-{}", name, std::process::id()));
+
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+
+    dir
+}
+
+/// Task from 9:00 to 10:30 on 19.10.2026, the end comment is appended to the name as on End
+#[cfg(test)]
+pub fn test_task(
+    name: &str,
+    tag: Option<&str>,
+    project: Option<&str>,
+    comment: Option<&str>,
+) -> CompletedTask {
+    use chrono::{Duration, TimeZone};
+
+    let dt_start = Local.with_ymd_and_hms(2026, 10, 19, 9, 0, 0).unwrap();
+
+    CompletedTask {
+        name: match comment {
+            Some(comment) => format!("{}. {}", name, comment),
+            None => name.to_owned(),
+        },
+        dt_start,
+        dt_end: dt_start + Duration::minutes(90),
+        end_comment: comment.map(|comment| comment.to_owned()),
+        issue: None,
+        tag: tag.map(|tag| tag.to_owned()),
+        project: project.map(|project| project.to_owned()),
+        plan_item: None,
+    }
+}
@@ -0,0 +1,363 @@
+use std::fs::File;
+use std::io::prelude::*;
+use std::path::PathBuf;
+
+use chrono::prelude::*;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+
+use crate::{config::WorklogConfig, export::worklog::WorklogEntry};
+
+const ISSUE_KEY_PLACEHOLDER: &str = "{issueKey}";
+
+/// Worklog entries accepted by the endpoint, kept so that a replay never sends them twice
+#[derive(Default, Serialize, Deserialize)]
+pub struct SentWorklogs {
+    pub entries: Vec<SentWorklog>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SentWorklog {
+    pub issue_key: String,
+    pub started: String,
+    pub time_spent_seconds: i64,
+    pub sent_at: DateTime<Local>,
+}
+
+/// Posts worklog JSON bodies one by one
+pub struct WorklogUploader {
+    pub endpoint: String,
+    pub authorization: Option<String>,
+}
+
+impl SentWorklogs {
+    pub fn init(file_path: &PathBuf) -> Result<SentWorklogs, String> {
+        if !file_path.exists() {
+            return Ok(SentWorklogs::default());
+        }
+
+        let mut string = String::new();
+
+        let read_result =
+            File::open(file_path).and_then(|mut file| file.read_to_string(&mut string));
+
+        if read_result.is_err() {
+            return Err("Could not read sent worklogs file".to_owned());
+        }
+
+        serde_json::from_str(&string).map_err(|_| "Sent worklogs file is not valid JSON".to_owned())
+    }
+
+    pub fn save(&self, file_path: &PathBuf) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|_| "Could not serialize sent worklogs".to_owned())?;
+
+        File::create(file_path)
+            .and_then(|mut file| file.write_all(json.as_bytes()))
+            .map_err(|_| "Could not write sent worklogs file".to_owned())
+    }
+
+    /// An entry is the time of one issue for one day, so the issue and the start identify it.
+    /// Returns the seconds already sent for it, a snapshot may have sent part of the day
+    pub fn sent_seconds(&self, entry: &WorklogEntry) -> i64 {
+        self.entries
+            .iter()
+            .filter(|sent| sent.issue_key == entry.issue_key && sent.started == entry.started)
+            .map(|sent| sent.time_spent_seconds)
+            .sum()
+    }
+
+    /// Entries with the time that was not sent yet. The latest total of an entry is the largest
+    /// one, when it grew since the last send only the difference is pending. An entry that has
+    /// less time than was already sent can not be fixed by a new worklog, it is reported instead
+    pub fn pending(&self, entries: Vec<WorklogEntry>) -> (Vec<WorklogEntry>, Vec<String>) {
+        let mut pending_entries = vec![];
+        let mut conflicts = vec![];
+
+        let latest_entries = entries
+            .into_iter()
+            .into_group_map_by(|entry| (entry.issue_key.to_owned(), entry.started.to_owned()))
+            .into_values()
+            .filter_map(|entries| {
+                entries
+                    .into_iter()
+                    .max_by_key(|entry| entry.time_spent_seconds)
+            })
+            .sorted_by(|a, b| {
+                a.started
+                    .cmp(&b.started)
+                    .then(a.issue_key.cmp(&b.issue_key))
+            });
+
+        for entry in latest_entries {
+            let sent_seconds = self.sent_seconds(&entry);
+            let pending_seconds = entry.time_spent_seconds - sent_seconds;
+
+            if pending_seconds > 0 {
+                pending_entries.push(WorklogEntry {
+                    time_spent_seconds: pending_seconds,
+                    ..entry
+                });
+            } else if pending_seconds < 0 {
+                conflicts.push(format!(
+                    "{} {}: {}s already sent, the file has only {}s, fix the worklog manually",
+                    entry.issue_key, entry.started, sent_seconds, entry.time_spent_seconds
+                ));
+            }
+        }
+
+        (pending_entries, conflicts)
+    }
+
+    pub fn record(&mut self, entry: &WorklogEntry) {
+        self.entries.push(SentWorklog {
+            issue_key: entry.issue_key.to_owned(),
+            started: entry.started.to_owned(),
+            time_spent_seconds: entry.time_spent_seconds,
+            sent_at: Local::now(),
+        });
+    }
+}
+
+impl WorklogUploader {
+    pub fn from_config(config: &WorklogConfig) -> Result<WorklogUploader, String> {
+        match &config.endpoint {
+            Some(endpoint) => Ok(WorklogUploader {
+                endpoint: endpoint.to_owned(),
+                authorization: config.authorization.to_owned(),
+            }),
+            None => Err("worklog.endpoint is not set in config.json".to_owned()),
+        }
+    }
+
+    /// `{issueKey}` in the endpoint is replaced, e.g. `https://jira/rest/api/2/issue/{issueKey}/worklog`
+    pub fn url(&self, entry: &WorklogEntry) -> String {
+        self.endpoint
+            .replace(ISSUE_KEY_PLACEHOLDER, &entry.issue_key)
+    }
+
+    pub fn send(&self, entry: &WorklogEntry) -> Result<(), String> {
+        let body = serde_json::to_string(entry)
+            .map_err(|_| "Could not serialize worklog entry".to_owned())?;
+
+        let mut request = ureq::post(&self.url(entry)).set("Content-Type", "application/json");
+
+        if let Some(authorization) = &self.authorization {
+            request = request.set("Authorization", authorization);
+        }
+
+        match request.send_string(&body) {
+            Ok(_) => Ok(()),
+            Err(ureq::Error::Status(status, _)) => Err(format!(
+                "{} {}: endpoint responded with {}",
+                entry.issue_key, entry.started, status
+            )),
+            Err(_) => Err(format!(
+                "{} {}: could not reach {}",
+                entry.issue_key,
+                entry.started,
+                self.url(entry)
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader};
+    use std::net::TcpListener;
+    use std::path::Path;
+    use std::sync::mpsc::{channel, Receiver};
+    use std::thread;
+
+    use super::*;
+    use crate::{cli, paths::AppPaths, utils::test_dir};
+
+    struct RecordedRequest {
+        request_line: String,
+        headers: Vec<(String, String)>,
+        body: String,
+    }
+
+    impl RecordedRequest {
+        fn header(&self, name: &str) -> Option<&str> {
+            self.headers
+                .iter()
+                .find(|(header, _)| header.eq_ignore_ascii_case(name))
+                .map(|(_, value)| value.as_str())
+        }
+    }
+
+    /// Answers every request with `status` and hands the requests over to the test
+    fn start_mock_server(status: u16) -> (String, Receiver<RecordedRequest>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let (sender, receiver) = channel();
+
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(mut stream) = stream else {
+                    return;
+                };
+
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+
+                let mut headers = vec![];
+
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+
+                    match line.trim_end().split_once(": ") {
+                        Some((name, value)) => headers.push((name.to_owned(), value.to_owned())),
+                        None => break,
+                    }
+                }
+
+                let content_length = headers
+                    .iter()
+                    .find(|(name, _)| name.eq_ignore_ascii_case("Content-Length"))
+                    .map(|(_, value)| value.parse().unwrap())
+                    .unwrap_or(0);
+
+                let mut body = vec![0; content_length];
+                reader.read_exact(&mut body).unwrap();
+
+                let _ = sender.send(RecordedRequest {
+                    request_line: request_line.trim_end().to_owned(),
+                    headers,
+                    body: String::from_utf8(body).unwrap(),
+                });
+
+                let response = format!(
+                    "HTTP/1.1 {} Mock\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+                    status
+                );
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+        });
+
+        (format!("http://{}", address), receiver)
+    }
+
+    fn entry(issue_key: &str, time_spent_seconds: i64) -> WorklogEntry {
+        WorklogEntry {
+            issue_key: issue_key.to_owned(),
+            started: "2026-10-19T09:00:00.000+0000".to_owned(),
+            time_spent_seconds,
+            comment: "Code review".to_owned(),
+        }
+    }
+
+    fn write_worklog_file(dir: &Path, file_name: &str, entries: &[WorklogEntry]) -> String {
+        let file_path = dir.join(file_name);
+
+        std::fs::write(&file_path, serde_json::to_string(entries).unwrap()).unwrap();
+
+        file_path.to_string_lossy().to_string()
+    }
+
+    fn send_worklog(dir: &Path, input: &str, endpoint: &str) -> Result<String, String> {
+        let args: Vec<String> = ["send-worklog", "--input", input, "--endpoint", endpoint]
+            .iter()
+            .map(|arg| arg.to_string())
+            .collect();
+
+        cli::run(&args, &AppPaths::in_dir(dir))
+    }
+
+    #[test]
+    fn send_posts_entry_to_issue_url_with_authorization() {
+        let (server_url, requests) = start_mock_server(201);
+
+        let uploader = WorklogUploader {
+            endpoint: format!("{}/rest/api/2/issue/{{issueKey}}/worklog", server_url),
+            authorization: Some("Bearer secret".to_owned()),
+        };
+
+        assert_eq!(uploader.send(&entry("PROJ-42", 3600)), Ok(()));
+
+        let request = requests.recv().unwrap();
+        let body: serde_json::Value = serde_json::from_str(&request.body).unwrap();
+
+        assert_eq!(
+            request.request_line,
+            "POST /rest/api/2/issue/PROJ-42/worklog HTTP/1.1"
+        );
+        assert_eq!(request.header("Authorization"), Some("Bearer secret"));
+        assert_eq!(request.header("Content-Type"), Some("application/json"));
+        assert_eq!(body["issueKey"], "PROJ-42");
+        assert_eq!(body["started"], "2026-10-19T09:00:00.000+0000");
+        assert_eq!(body["timeSpentSeconds"], 3600);
+        assert_eq!(body["comment"], "Code review");
+    }
+
+    #[test]
+    fn send_turns_error_status_into_err() {
+        let (server_url, _requests) = start_mock_server(400);
+
+        let uploader = WorklogUploader {
+            endpoint: format!("{}/{{issueKey}}", server_url),
+            authorization: None,
+        };
+
+        let result = uploader.send(&entry("PROJ-42", 3600));
+
+        assert!(result.unwrap_err().contains("400"));
+    }
+
+    #[test]
+    fn second_run_sends_nothing() {
+        let dir = test_dir("second-run");
+        let (server_url, requests) = start_mock_server(201);
+        let endpoint = format!("{}/{{issueKey}}", server_url);
+        let input = write_worklog_file(&dir, "day.worklog.json", &[entry("PROJ-42", 3600)]);
+
+        assert!(send_worklog(&dir, &input, &endpoint).is_ok());
+        assert!(requests.recv().is_ok());
+
+        let message = send_worklog(&dir, &input, &endpoint).unwrap();
+
+        assert!(message.starts_with("0 entries to send"));
+        assert!(requests.try_recv().is_err());
+    }
+
+    #[test]
+    fn grown_entry_sends_only_the_difference() {
+        let dir = test_dir("grown-entry");
+        let (server_url, requests) = start_mock_server(201);
+        let endpoint = format!("{}/{{issueKey}}", server_url);
+
+        let snapshot = write_worklog_file(&dir, "snapshot.json", &[entry("PROJ-42", 3600)]);
+        let end_of_day = write_worklog_file(&dir, "day.json", &[entry("PROJ-42", 5400)]);
+
+        assert!(send_worklog(&dir, &snapshot, &endpoint).is_ok());
+        assert!(send_worklog(&dir, &end_of_day, &endpoint).is_ok());
+
+        // Requests are recorded before the response, so both are there once the runs return
+        let sent_seconds: Vec<i64> = requests
+            .try_iter()
+            .map(|request| {
+                let body: serde_json::Value = serde_json::from_str(&request.body).unwrap();
+
+                body["timeSpentSeconds"].as_i64().unwrap()
+            })
+            .collect();
+
+        assert_eq!(sent_seconds, vec![3600, 1800]);
+    }
+
+    #[test]
+    fn shrunk_entry_is_reported_as_conflict() {
+        let mut sent_worklogs = SentWorklogs::default();
+        sent_worklogs.record(&entry("PROJ-42", 3600));
+
+        let (pending_entries, conflicts) = sent_worklogs.pending(vec![entry("PROJ-42", 1800)]);
+
+        assert!(pending_entries.is_empty());
+        assert_eq!(conflicts.len(), 1);
+    }
+}